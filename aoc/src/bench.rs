@@ -0,0 +1,102 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use common::ParseError;
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// Timings for one stage of one day, over every iteration of a benchmark run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub stage: String,
+    pub iterations: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Record {
+    fn new(day: u8, stage: &str, mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+
+        Self {
+            day,
+            stage: stage.to_string(),
+            iterations: samples.len() as u32,
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+// runs `f` `iterations` times, stopping at the first error
+fn time<T>(iterations: u32, mut f: impl FnMut() -> Result<T, ParseError>) -> Result<Vec<Duration>, ParseError> {
+    (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            std::hint::black_box(result?);
+            Ok(elapsed)
+        })
+        .collect()
+}
+
+/// Times parsing and solving both parts of `day`. The parts parse the input themselves, so the
+/// median parse is taken off each of their runs to leave the time spent solving.
+pub fn day(day: u8, solvers: &Day, input: &str, iterations: u32) -> Result<Vec<Record>, ParseError> {
+    let parse = time(iterations, || (solvers.parse)(input))?;
+    let parse_median = Record::new(day, "parse", parse.clone()).median_ns;
+    let solve = |samples: Vec<Duration>| {
+        samples.into_iter().map(|sample| sample.saturating_sub(Duration::from_nanos(parse_median))).collect()
+    };
+
+    Ok(vec![
+        Record::new(day, "parse", parse),
+        Record::new(day, "solve_1", solve(time(iterations, || (solvers.part_1)(input))?)),
+        Record::new(day, "solve_2", solve(time(iterations, || (solvers.part_2)(input))?)),
+    ])
+}
+
+pub fn print(records: &[Record], previous: &[Record]) {
+    println!("solve_1 and solve_2 are each part's time with the median parse taken off");
+    println!(
+        "{:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "day", "stage", "min", "median", "max", "change"
+    );
+
+    for record in records {
+        let change = previous
+            .iter()
+            .find(|p| p.day == record.day && p.stage == record.stage)
+            .map(|p| {
+                let change = (record.median_ns as f64 / p.median_ns.max(1) as f64 - 1.0) * 100.0;
+                format!("{:+.1}%", change)
+            })
+            .unwrap_or_default();
+
+        println!(
+            "{:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>8}",
+            record.day,
+            record.stage,
+            format!("{:.2?}", Duration::from_nanos(record.min_ns)),
+            format!("{:.2?}", Duration::from_nanos(record.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(record.max_ns)),
+            change,
+        );
+    }
+}
+
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("could not parse {}: {}", path.display(), e))
+}
+
+pub fn save(path: &Path, records: &[Record]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(records).map_err(|e| e.to_string())?;
+    std::fs::write(path, json + "\n").map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
@@ -0,0 +1,73 @@
+use common::ParseError;
+use recorder::Recorder;
+
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+pub type Parser = fn(&str) -> Result<(), ParseError>;
+pub type Simulation = fn(&str, u8, &mut Recorder) -> Result<(), ParseError>;
+
+pub struct Day {
+    pub parse: Parser,
+    pub part_1: Solver,
+    pub part_2: Solver,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Solver {
+        match part {
+            1 => self.part_1,
+            _ => self.part_2,
+        }
+    }
+}
+
+// wraps a typed `part_N` function so every answer comes back as a string
+macro_rules! solver {
+    ($f:path) => {
+        |input: &str| $f(input).map(|answer| answer.to_string())
+    };
+}
+
+pub fn get(day: u8) -> Option<Day> {
+    let (parse, part_1, part_2): (Parser, Solver, Solver) = match day {
+        1 => (day_1a::parse, solver!(day_1a::part_1), solver!(day_1a::part_2)),
+        2 => (day_2a::parse, solver!(day_2a::part_1), solver!(day_2a::part_2)),
+        3 => (day_3a::parse, solver!(day_3a::part_1), solver!(day_3a::part_2)),
+        4 => (day_4a::parse, solver!(day_4a::part_1), solver!(day_4a::part_2)),
+        5 => (day_5a::parse, solver!(day_5a::part_1), solver!(day_5a::part_2)),
+        6 => (day_6a::parse, solver!(day_6a::part_1), solver!(day_6a::part_2)),
+        7 => (day_7a::parse, solver!(day_7a::part_1), solver!(day_7a::part_2)),
+        8 => (day_8a::parse, solver!(day_8a::part_1), solver!(day_8a::part_2)),
+        9 => (day_9a::parse, solver!(day_9a::part_1), solver!(day_9a::part_2)),
+        10 => (day_10a::parse, solver!(day_10a::part_1), solver!(day_10a::part_2)),
+        11 => (day_11a::parse, solver!(day_11a::part_1), solver!(day_11a::part_2)),
+        12 => (day_12a::parse, solver!(day_12a::part_1), solver!(day_12a::part_2)),
+        13 => (day_13a::parse, solver!(day_13a::part_1), solver!(day_13a::part_2)),
+        14 => (day_14a::parse, solver!(day_14a::part_1), solver!(day_14a::part_2)),
+        15 => (day_15a::parse, solver!(day_15a::part_1), solver!(day_15a::part_2)),
+        16 => (day_16a::parse, solver!(day_16a::part_1), solver!(day_16a::part_2)),
+        17 => (day_17a::parse, solver!(day_17a::part_1), solver!(day_17a::part_2)),
+        18 => (day_18a::parse, solver!(day_18a::part_1), solver!(day_18a::part_2)),
+        19 => (day_19a::parse, solver!(day_19a::part_1), solver!(day_19a::part_2)),
+        20 => (day_20a::parse, solver!(day_20a::part_1), solver!(day_20a::part_2)),
+        21 => (day_21a::parse, solver!(day_21a::part_1), solver!(day_21a::part_2)),
+        22 => (day_22a::parse, solver!(day_22a::part_1), solver!(day_22a::part_2)),
+        23 => (day_23a::parse, solver!(day_23a::part_1), solver!(day_23a::part_2)),
+        24 => (day_24a::parse, solver!(day_24a::part_1), solver!(day_24a::part_2)),
+        25 => (day_25a::parse, solver!(day_25a::part_1), solver!(day_25a::part_2)),
+        _ => return None,
+    };
+
+    Some(Day { parse, part_1, part_2 })
+}
+
+/// The days whose solvers step through a grid simulation that can be recorded.
+pub fn simulation(day: u8) -> Option<Simulation> {
+    match day {
+        6 => Some(day_6a::record),
+        9 => Some(day_9a::record),
+        14 => Some(day_14a::record),
+        15 => Some(day_15a::record),
+        16 => Some(day_16a::record),
+        _ => None,
+    }
+}
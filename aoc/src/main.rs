@@ -0,0 +1,252 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use recorder::Recorder;
+use serde::Serialize;
+
+mod bench;
+mod days;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Show what the solvers are doing on stderr; repeat for more detail
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `Day N part N: answer` lines
+    Text,
+    /// One JSON object per answer, with its day, part, answer and elapsed seconds
+    Json,
+}
+
+#[derive(Serialize)]
+struct Answer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed: f64,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solver against an input file
+    Run {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to run; both parts are run when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, defaults to the day's own input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Time parsing and solving both parts of one day, or of every day
+    Bench {
+        /// Day to time; every day is timed when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Number of times each stage is run
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: u32,
+        /// Input file, defaults to the day's own input.txt
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Write the timings to this file as JSON
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare the medians against the JSON written by an earlier run
+        #[arg(long)]
+        compare: Option<PathBuf>,
+    },
+    /// Print a random input for a day
+    Gen {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How big the input is, in a unit that depends on the day; defaults to about the
+        /// size of a real input
+        #[arg(short, long)]
+        size: Option<usize>,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Record a day's simulation as PPM frames or an animated GIF
+    Record {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, defaults to the day's own input.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Steps to leave out before the first frame
+        #[arg(long, default_value_t = 0)]
+        skip: usize,
+        /// Keep one step in this many
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// Stop recording after this many frames
+        #[arg(long, default_value_t = usize::MAX)]
+        limit: usize,
+        /// Pixels per grid cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Write the frames as numbered PPM images into this directory
+        #[arg(long, required_unless_present = "gif")]
+        ppm: Option<PathBuf>,
+        /// Write the frames as an animated GIF
+        #[arg(long)]
+        gif: Option<PathBuf>,
+        /// GIF frame delay, in hundredths of a second
+        #[arg(long, default_value_t = 5)]
+        delay: u16,
+    },
+}
+
+fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{}a", day))
+        .join("input.txt")
+}
+
+fn run(day: u8, part: Option<u8>, input: Option<PathBuf>, format: Format) -> Result<(), String> {
+    let solvers = days::get(day).ok_or_else(|| format!("day {} does not exist", day))?;
+
+    let path = input.unwrap_or_else(|| default_input(day));
+    let input = std::fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for part in parts {
+        let solve = solvers.part(part);
+        let start = Instant::now();
+        let answer = solve(&input)
+            .map_err(|e| format!("could not parse {}: {}", path.display(), e.render(&input)))?;
+        let elapsed = start.elapsed().as_secs_f64();
+
+        match format {
+            Format::Text => println!("Day {} part {}: {}", day, part, answer),
+            Format::Json => {
+                let answer = Answer { day, part, answer: &answer, elapsed };
+                println!("{}", serde_json::to_string(&answer).map_err(|e| e.to_string())?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(
+    day: Option<u8>,
+    iterations: u32,
+    input: Option<PathBuf>,
+    json: Option<PathBuf>,
+    compare: Option<PathBuf>,
+) -> Result<(), String> {
+    let previous = match compare {
+        Some(path) => bench::load(&path)?,
+        None => Vec::new(),
+    };
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    let mut records = Vec::new();
+    for day in days {
+        let solvers = days::get(day).ok_or_else(|| format!("day {} does not exist", day))?;
+
+        let path = input.clone().unwrap_or_else(|| default_input(day));
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+
+        let timings = bench::day(day, &solvers, &input, iterations)
+            .map_err(|e| format!("could not parse {}: {}", path.display(), e.render(&input)))?;
+        records.extend(timings);
+    }
+
+    bench::print(&records, &previous);
+
+    if let Some(path) = json {
+        bench::save(&path, &records)?;
+    }
+
+    Ok(())
+}
+
+fn gen(day: u8, size: Option<usize>, seed: u64) -> Result<(), String> {
+    let input = generators::generate(day, size, seed).ok_or_else(|| format!("day {} does not exist", day))?;
+    print!("{}", input);
+    Ok(())
+}
+
+struct Output {
+    scale: usize,
+    ppm: Option<PathBuf>,
+    gif: Option<PathBuf>,
+    delay: u16,
+}
+
+fn record(day: u8, part: u8, input: Option<PathBuf>, mut recorder: Recorder, output: Output) -> Result<(), String> {
+    let simulate = days::simulation(day).ok_or_else(|| format!("day {} has no simulation to record", day))?;
+
+    let path = input.unwrap_or_else(|| default_input(day));
+    let input = std::fs::read_to_string(&path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+
+    simulate(&input, part, &mut recorder)
+        .map_err(|e| format!("could not parse {}: {}", path.display(), e.render(&input)))?;
+
+    if let Some(dir) = output.ppm {
+        recorder
+            .write_ppm(&dir, output.scale)
+            .map_err(|e| format!("could not write {}: {}", dir.display(), e))?;
+    }
+    if let Some(file) = output.gif {
+        recorder
+            .write_gif(&file, output.scale, output.delay)
+            .map_err(|e| format!("could not write {}: {}", file.display(), e))?;
+    }
+
+    eprintln!("recorded {} frames", recorder.len());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    common::set_verbosity(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run { day, part, input, format } => run(day, part, input, format),
+        Command::Bench { day, iterations, input, json, compare } => bench(day, iterations, input, json, compare),
+        Command::Gen { day, size, seed } => gen(day, size, seed),
+        Command::Record { day, part, input, skip, every, limit, scale, ppm, gif, delay } => {
+            let output = Output { scale, ppm, gif, delay };
+            record(day, part, input, Recorder::new(skip, every, limit), output)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
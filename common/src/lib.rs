@@ -0,0 +1,174 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// An input that couldn't be parsed, and where in the input it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// the offending text, empty when something was missing
+    pub text: String,
+    /// what the parser wanted to find instead
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error for `part`, which has to be a slice of `input` so its line and column can be found.
+    pub fn at(input: &str, part: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, part);
+        let before = &input[..offset];
+
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::new(line, column, part, expected)
+    }
+
+    /// Something was missing straight after `part` (usually the end of a line).
+    pub fn after(input: &str, part: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &part[part.len()..], expected)
+    }
+
+    /// Something was missing from the end of the input.
+    pub fn missing(input: &str, expected: impl Into<String>) -> Self {
+        Self::after(input, input.trim_end(), expected)
+    }
+
+    /// The error along with the line it happened on and a marker under the offending text.
+    pub fn render(&self, input: &str) -> String {
+        let source = input.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        let width = self.text.lines().next().unwrap_or("").chars().count().max(1);
+        let marker = format!("{}{}", " ".repeat(self.column - 1), "^".repeat(width));
+
+        format!("{}\n{} |\n{} | {}\n{} | {}", self, gutter, number, source, gutter, marker)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        match self.text.lines().next() {
+            Some(text) if !text.is_empty() => write!(f, "found `{}`", text),
+            _ => write!(f, "found nothing"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn offset_in(input: &str, part: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let at = part.as_ptr() as usize;
+    debug_assert!(at >= start && at <= start + input.len(), "part is not a slice of the input");
+
+    at.saturating_sub(start).min(input.len())
+}
+
+/// Parses `part`, a slice of `input`, pointing at it if it isn't valid.
+pub fn parse<T: FromStr>(input: &str, part: &str, expected: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| ParseError::at(input, part, expected))
+}
+
+/// The next item from `fields`, which were split out of `whole`.
+/// When there are none left the error points at the end of `whole`.
+pub fn next_field<'a>(
+    input: &str,
+    whole: &'a str,
+    fields: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    fields.next().ok_or_else(|| ParseError::after(input, whole, expected))
+}
+
+/// For the day binaries: prints the diagnostic and exits if the input couldn't be parsed.
+pub fn or_exit<T>(result: Result<T, ParseError>, input: &str) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("error: {}", e.render(input));
+            std::process::exit(1);
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// How much the solvers say on stderr while they work. At 0 (the default) they say nothing,
+/// at 1 they show what they found (maps, best candidates) and at 2 they also report progress.
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Like `eprintln!`, but only when the verbosity is at least `level`, so stdout stays
+/// free for answers.
+#[macro_export]
+macro_rules! diag {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::verbosity() >= $level {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// For the golden tests: the answer recorded for `part` in a day's `answers.txt`,
+/// which has one line per part like `part_1: 1234`.
+pub fn answer(answers: &str, part: u8) -> &str {
+    let prefix = format!("part_{}: ", part);
+    answers
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .unwrap_or_else(|| panic!("answers.txt has no `{}` line", prefix.trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_line_and_column() {
+        let input = "1 2\n3 x4\n";
+        let part = &input[6..8];
+        let e = ParseError::at(input, part, "a number");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x4"));
+    }
+
+    #[test]
+    fn missing_field_points_after_line() {
+        let input = "1 2\n3\n";
+        let line = input.lines().nth(1).unwrap();
+        let mut fields = line.split_whitespace();
+        fields.next();
+        let e = next_field(input, line, &mut fields, "a second number").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, ""));
+    }
+
+    #[test]
+    fn renders_marker() {
+        let input = "1 2\n3 x4\n";
+        let e = parse::<i32>(input, &input[6..8], "a number").unwrap_err();
+        assert_eq!(
+            e.render(input),
+            "line 2, column 3: expected a number, found `x4`\n  |\n2 | 3 x4\n  |   ^^"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use common::ParseError;
+use grid::{Grid, Point};
+
+#[derive(Debug, Clone)]
+struct Trail {
+    map: Grid<u8>,
+    heads: Vec<Point>,
+}
+
+impl Trail {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        // big block of numbers
+        let map = Grid::parse(input, |_, c| c.to_digit(10).map(|d| d as u8), "a height from 0 to 9")?;
+
+        // find all heads (all 0)
+        let heads = map
+            .iter()
+            .filter(|(_, &cell)| cell == 0)
+            .map(|(p, _)| p)
+            .collect();
+
+        Ok(Self { map, heads })
+    }
+}
+
+impl Display for Trail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Trail::new(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let trail = Trail::new(input)?;
+
+    Ok(find_paths(&trail, true))
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    // create a new Trail
+    let trail = Trail::new(input)?;
+
+    // find all paths
+    Ok(find_paths(&trail, false))
+}
+
+// when `distinct` is set, a head only scores once for each 9 it can reach
+fn find_paths(trail: &Trail, distinct: bool) -> usize {
+    // loop over heads
+    let mut count = 0;
+    for head in &trail.heads {
+        //println!("Head: {:?}", head);
+        let mut ends = Vec::new();
+        recurse(trail, *head, &mut ends);
+        if distinct {
+            ends.sort();
+            ends.dedup();
+        }
+        count += ends.len();
+        //println!("Scored: {}", ends.len());
+    }
+
+    count
+}
+
+// pushes the 9 at the end of every path from position
+fn recurse(trail: &Trail, position: Point, ends: &mut Vec<Point>) {
+    let current_value = trail.map[position];
+
+    // if we hit a 9, we're done
+    if current_value == 9 {
+        ends.push(position);
+        return;
+    }
+
+    for next in trail.map.neighbours(position) {
+        if trail.map[next] == current_value + 1 {
+            recurse(trail, next, ends);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 36);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 81);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,137 @@
+use cached::proc_macro::cached;
+use common::ParseError;
+
+fn create_vec(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .trim_end()
+        .split(' ')
+        .map(|x| common::parse::<u32>(input, x, "a stone number").map(i64::from))
+        .collect()
+}
+
+/*
+fn run_rules(vec: &mut Vec<i64>) {
+    let mut i = 0;
+    while i < vec.len() {
+        let (a, b) = rules(vec[i]);
+        vec[i] = a;
+        if let Some(b) = b {
+            vec.insert(i + 1, b);
+            i += 1;
+        }
+        i += 1;
+    }
+}
+
+fn rules(item: i64) -> (i64, Option<i64>) {
+    if item == 0 { return (1, None); }
+
+    // convert to string
+    let s = item.to_string();
+    if s.len() % 2 == 0 {
+        // split in 2
+        let (a, b) = s.split_at(s.len() / 2);
+        let a = a.parse::<i64>().unwrap();
+        let b = b.parse::<i64>().unwrap();
+
+        return (a, Some(b));
+    }
+
+    return (item * 2024, None);
+}
+*/
+
+#[cached]
+fn find_eventual_length(item: i64, steps: i32) -> usize {
+    if steps == 0 { return 1; }
+    let as_string = item.to_string();
+    if as_string.len().is_multiple_of(2) {
+        return
+            find_eventual_length(as_string[0..as_string.len() / 2].parse::<i64>().unwrap(), steps - 1) +
+            find_eventual_length(as_string[as_string.len() / 2..].parse::<i64>().unwrap(), steps - 1);
+    }
+
+    match item {
+        0 => find_eventual_length(1, steps - 1),
+        _ => find_eventual_length(item * 2024, steps - 1),
+    }
+}
+
+fn blink(input: &str, blinks: i32) -> Result<usize, ParseError> {
+    let vec = create_vec(input)?;
+
+    let mut length = 0;
+    let mut i = 0;
+    for item in vec {
+        length += find_eventual_length(item, blinks);
+        i += 1;
+        common::diag!(2, "{} stones done", i);
+    }
+    Ok(length)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    create_vec(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    blink(input, 25)
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    blink(input, 75)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // the puzzle only gives an example answer for 25 blinks
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 55312);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    // keeps every stone in the line and applies the rules to each one, like the puzzle describes
+    fn blink_naive(stone: i64, blinks: i32) -> usize {
+        let mut stones = vec![stone];
+        for _ in 0..blinks {
+            stones = stones
+                .into_iter()
+                .flat_map(|stone| {
+                    let digits = stone.to_string();
+                    if stone == 0 {
+                        vec![1]
+                    } else if digits.len().is_multiple_of(2) {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    } else {
+                        vec![stone * 2024]
+                    }
+                })
+                .collect();
+        }
+        stones.len()
+    }
+
+    proptest! {
+        #[test]
+        fn eventual_length_matches_naive(stone in 0_i64..1_000_000, blinks in 0_i32..16) {
+            prop_assert_eq!(super::find_eventual_length(stone, blinks), blink_naive(stone, blinks));
+        }
+    }
+}
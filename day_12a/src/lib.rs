@@ -0,0 +1,188 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use common::ParseError;
+use grid::{Dir, Grid, Point};
+
+#[derive(Debug, Clone)]
+struct Garden {
+    map: Grid<char>,
+    debug: Grid<char>,
+}
+
+impl Garden {
+    fn new(map: Grid<char>) -> Self {
+        Self { map: map.clone(), debug: map }
+    }
+
+    fn generate(input: &str) -> Result<Self, ParseError> {
+        // every plot is marked with the letter of its plant
+        let map = Grid::parse(input, |_, c| c.is_ascii_alphabetic().then_some(c), "a plant letter")?;
+        Ok(Self::new(map))
+    }
+
+    pub fn print(&self, debug: bool) {
+        if !debug {
+            eprint!("{}", self.map);
+        } else {
+            eprint!("{}", self.debug);
+        }
+    }
+
+    fn get_cell(&self, p: Point) -> Option<char> {
+        self.map.get(p).copied()
+    }
+
+    fn get_debug_cell(&self, p: Point) -> Option<char> {
+        self.debug.get(p).copied()
+    }
+
+    fn set_debug_cell(&mut self, p: Point, value: char) {
+        if let Some(cell) = self.debug.get_mut(p) {
+            *cell = value;
+        }
+    }
+
+    fn find_area_of_region_recursive(&self, p: Point, visited: &mut HashSet<Point>) -> i64 {
+        visited.insert(p);
+
+        let self_cell = self.map[p];
+
+        let mut area = 1;
+        for next in self.map.neighbours(p) {
+            if self.map[next] == self_cell && !visited.contains(&next) {
+                area += self.find_area_of_region_recursive(next, visited);
+            }
+        }
+
+        area
+    }
+
+    fn find_perimeter_of_region_recursive(&self, p: Point, visited: &mut HashSet<Point>) -> i64 {
+        visited.insert(p);
+
+        let self_cell = self.map[p];
+
+        let mut perimeter = 0;
+        for next in p.neighbours() {
+            // off the edge of the map counts as a different plant
+            if self.get_cell(next) != Some(self_cell) {
+                perimeter += 1;
+            } else if !visited.contains(&next) {
+                perimeter += self.find_perimeter_of_region_recursive(next, visited);
+            }
+        }
+
+        perimeter
+    }
+
+    fn find_sides_of_region_wrapper(&mut self, p: Point, visited: &mut HashSet<Point>) -> i64 {  
+        let items = self.find_sides_of_region_recursive(p, visited);
+        
+        let mut sides = 0;
+
+        for (p, dir) in items.iter() {
+            // a side is counted at its right or bottom end
+            let along = match *dir {
+                Dir::Up | Dir::Down => Point::new(1, 0),
+                Dir::Left | Dir::Right => Point::new(0, 1),
+            };
+
+            if !items.contains(&(*p + along, *dir)) {
+                sides += 1;
+            }
+        }
+
+        sides
+    }
+
+    fn find_sides_of_region_recursive(&mut self, p: Point, visited: &mut HashSet<Point>) -> HashSet<(Point, Dir)> {
+        visited.insert(p);
+
+        let self_cell = self.map[p];
+
+        let mut cells = HashSet::new();
+
+        for direction in Dir::ALL {
+            let next = p.step(direction);
+            if self.get_cell(next) != Some(self_cell) {
+                cells.insert((p, direction));
+            } else if !visited.contains(&next) {
+                cells.extend(self.find_sides_of_region_recursive(next, visited));
+            }
+        }
+
+        cells
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Garden::generate(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let garden = Garden::generate(input)?;
+
+    let mut area_visited = HashSet::new();
+    let mut perimeter_visited = HashSet::new();
+    let mut total_cost = 0;
+    for p in garden.map.points() {
+        if !area_visited.contains(&p) {
+            let area = garden.find_area_of_region_recursive(p, &mut area_visited);
+            let perimeter = garden.find_perimeter_of_region_recursive(p, &mut perimeter_visited);
+            total_cost += area * perimeter;
+        }
+    }
+
+    Ok(total_cost)
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let mut garden = Garden::generate(input)?;
+
+    let mut area_visited = HashSet::new();
+    let mut perimeter_visited = HashSet::new();
+    let mut total_cost = 0;
+    for p in garden.map.points() {
+        if !area_visited.contains(&p) {
+            let area = garden.find_area_of_region_recursive(p, &mut area_visited);
+            let sides = garden.find_sides_of_region_wrapper(p, &mut perimeter_visited);
+            common::diag!(2, "Area: {}, Sides: {}, Char: {}", area, sides, garden.map[p]);
+            total_cost += area * sides;
+        }
+    }
+
+    if common::verbosity() >= 1 {
+        garden.print(true);
+    }
+
+    Ok(total_cost)
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 1930);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 1206);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
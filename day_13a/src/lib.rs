@@ -0,0 +1,242 @@
+#![allow(dead_code)]
+
+use common::ParseError;
+
+#[derive(Debug, Clone)]
+struct Machine {
+    button_a: (f64, f64),
+    button_b: (f64, f64),
+    prize: (f64, f64),
+}
+
+impl Machine {
+    fn new() -> Machine {
+        Machine {
+            button_a: (0., 0.),
+            button_b: (0., 0.),
+            prize: (0., 0.),
+        }
+    }
+
+    fn parse(input: &str, lines: [&str; 3], offset: i64) -> Result<Self, ParseError> {
+        let (button_a_x, button_a_y) = parse_pair(input, lines[0], "Button A: X+", ", Y+")?;
+        let (button_b_x, button_b_y) = parse_pair(input, lines[1], "Button B: X+", ", Y+")?;
+        let (mut prize_x, mut prize_y) = parse_pair(input, lines[2], "Prize: X=", ", Y=")?;
+
+        prize_x += offset;
+        prize_y += offset;
+
+        Ok(Machine {
+            button_a: (button_a_x as f64, button_a_y as f64),
+            button_b: (button_b_x as f64, button_b_y as f64),
+            prize: (prize_x as f64, prize_y as f64),
+        })
+    }
+
+    fn find_amount(&self) -> Option<(i64, i64)> {
+        let det = self.button_a.1 * self.button_b.0 - self.button_a.0 * self.button_b.1;
+        if det == 0. {
+            return self.find_amount_in_line();
+        }
+
+        let b = (self.button_a.1 * self.prize.0 - self.button_a.0 * self.prize.1) / det;
+        let a = (self.prize.0 - b * self.button_b.0) / self.button_a.0;
+
+        if (a - a.round()).abs() > 0.0001 || (b - b.round()).abs() > 0.0001 {
+            return None;
+        }
+
+        // the prize can be behind the claw, but buttons can't be pressed a negative number of times
+        let (a, b) = (a.round() as i64, b.round() as i64);
+        if a < 0 || b < 0 {
+            return None;
+        }
+
+        Some((a, b))
+    }
+
+    // when both buttons move the claw the same way, the prize has to be that way too, and then
+    // there can be lots of ways to reach it. each one swaps some `a` presses for `b` presses,
+    // changing the cost the same amount every time, so the cheapest presses one of the buttons as
+    // few times as it can
+    fn find_amount_in_line(&self) -> Option<(i64, i64)> {
+        let (ax, ay) = (self.button_a.0 as i64, self.button_a.1 as i64);
+        let (bx, by) = (self.button_b.0 as i64, self.button_b.1 as i64);
+        let (px, py) = (self.prize.0 as i64, self.prize.1 as i64);
+
+        // the puzzle's buttons always move the claw forwards
+        if ax + ay <= 0 || bx + by <= 0 || ax * py != ay * px {
+            return None;
+        }
+
+        // everything is along one line, so how far along it things are can just be added up
+        let (a_step, b_step, prize) = (ax + ay, bx + by, px + py);
+        // the fewest presses of the button moving `step` that leave the rest to the other one.
+        // what's left repeats after `other` presses, so there's no point trying more
+        let fewest = |step: i64, other: i64| {
+            (0..=other).find_map(|presses| {
+                let left = prize - presses * step;
+                (left >= 0 && left % other == 0).then_some((presses, left / other))
+            })
+        };
+
+        // an `a` press costs 3 tokens and a `b` press 1, so `b` is cheaper unless `a` goes more
+        // than three times as far
+        if 3 * b_step >= a_step {
+            fewest(a_step, b_step)
+        } else {
+            fewest(b_step, a_step).map(|(b, a)| (a, b))
+        }
+    }
+}
+
+// the two numbers in a line like `Button A: X+94, Y+34`
+fn parse_pair(input: &str, line: &str, prefix: &str, separator: &str) -> Result<(i64, i64), ParseError> {
+    let rest = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, line, format!("a line starting with `{}`", prefix)))?;
+    let (x, y) = rest
+        .split_once(separator)
+        .ok_or_else(|| ParseError::at(input, rest, format!("two numbers separated by `{}`", separator)))?;
+
+    Ok((common::parse(input, x, "a number")?, common::parse(input, y, "a number")?))
+}
+
+fn parse_machines(input: &str, offset: i64) -> Result<Vec<Machine>, ParseError> {
+    // split by lines, machines are separated by an empty one
+    let mut lines = input.lines().filter(|line| !line.is_empty());
+
+    let mut machines = Vec::new();
+    while let Some(line_1) = lines.next() {
+        let line_2 = lines.next().ok_or_else(|| ParseError::after(input, line_1, "a `Button B` line"))?;
+        let line_3 = lines.next().ok_or_else(|| ParseError::after(input, line_2, "a `Prize` line"))?;
+        machines.push(Machine::parse(input, [line_1, line_2, line_3], offset)?);
+    }
+
+    Ok(machines)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_machines(input, 0).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let mut total_tokens = 0;
+    for machine in parse_machines(input, 0)? {
+        if let Some((a, b)) = machine.find_amount() {
+            // no button can be pressed more than 100 times
+            if a <= 100 && b <= 100 {
+                total_tokens += a * 3 + b;
+            }
+        }
+    }
+
+    Ok(total_tokens)
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let machines = parse_machines(input, 10000000000000)?;
+
+    let mut total_tokens = 0;
+    for machine in machines {
+        common::diag!(2, "{:?}", machine);
+        let (a, b) = match machine.find_amount() {
+            Some((a, b)) => (a, b),
+            None => {
+                continue;
+            }
+        };
+        total_tokens += a * 3 + b;
+    }
+
+    Ok(total_tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::Machine;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // the puzzle only gives an example answer for the unshifted prizes
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 480);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    // tries every number of `a` presses that doesn't overshoot, keeping the cheapest exact hit
+    fn find_amount_naive(machine: &Machine) -> Option<(i64, i64)> {
+        let (ax, ay) = (machine.button_a.0 as i64, machine.button_a.1 as i64);
+        let (bx, by) = (machine.button_b.0 as i64, machine.button_b.1 as i64);
+        let (px, py) = (machine.prize.0 as i64, machine.prize.1 as i64);
+
+        (0..=px / ax)
+            .filter_map(|a| {
+                let (rx, ry) = (px - a * ax, py - a * ay);
+                let b = rx / bx;
+                (rx % bx == 0 && ry >= 0 && b * by == ry).then_some((a, b))
+            })
+            .min_by_key(|(a, b)| a * 3 + b)
+    }
+
+    fn machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Machine {
+        Machine {
+            button_a: (a.0 as f64, a.1 as f64),
+            button_b: (b.0 as f64, b.1 as f64),
+            prize: (prize.0 as f64, prize.1 as f64),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn reachable_prize_matches_naive(
+            a in (1_i64..100, 1_i64..100),
+            b in (1_i64..100, 1_i64..100),
+            presses in (0_i64..=100, 0_i64..=100),
+        ) {
+            let prize = (presses.0 * a.0 + presses.1 * b.0, presses.0 * a.1 + presses.1 * b.1);
+            let machine = machine(a, b, prize);
+            prop_assert_eq!(machine.find_amount(), find_amount_naive(&machine));
+        }
+
+        #[test]
+        fn any_prize_matches_naive(
+            a in (1_i64..100, 1_i64..100),
+            b in (1_i64..100, 1_i64..100),
+            prize in (0_i64..20_000, 0_i64..20_000),
+        ) {
+            let machine = machine(a, b, prize);
+            prop_assert_eq!(machine.find_amount(), find_amount_naive(&machine));
+        }
+
+        // random buttons are hardly ever parallel, so these are made to be, with the prize
+        // somewhere along the same line or just off it
+        #[test]
+        fn parallel_buttons_match_naive(
+            direction in (1_i64..20, 1_i64..20),
+            lengths in (1_i64..8, 1_i64..8),
+            along in 0_i64..2_000,
+            off in prop_oneof![Just(0_i64), -1_i64..=1],
+        ) {
+            let a = (direction.0 * lengths.0, direction.1 * lengths.0);
+            let b = (direction.0 * lengths.1, direction.1 * lengths.1);
+            let prize = (direction.0 * along, direction.1 * along + off);
+            let machine = machine(a, b, prize);
+            prop_assert_eq!(machine.find_amount(), find_amount_naive(&machine));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+#![allow(dead_code)]
+
+use common::ParseError;
+use grid::{Grid, Point};
+use recorder::Recorder;
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    x: i32,
+    y: i32,
+    dx: i32,
+    dy: i32,
+}
+
+impl Robot {
+    pub fn new(x: i32, y: i32, dx: i32, dy: i32) -> Self {
+        Self { x, y, dx, dy }
+    }
+
+    pub fn parse_input(input: &str, line: &str) -> Result<Self, ParseError> {
+        // lines look like `p=0,4 v=3,-3`
+        let rest = line
+            .strip_prefix("p=")
+            .ok_or_else(|| ParseError::at(input, line, "a line starting with `p=`"))?;
+
+        let (pos_input, dir_input) = rest
+            .split_once(" v=")
+            .ok_or_else(|| ParseError::at(input, rest, "a position and ` v=` velocity"))?;
+
+        let (x, y) = parse_pair(input, pos_input)?;
+        let (dx, dy) = parse_pair(input, dir_input)?;
+
+        Ok(Self::new(x, y, dx, dy))
+    }
+}
+
+fn parse_pair(input: &str, pair: &str) -> Result<(i32, i32), ParseError> {
+    let (x, y) = pair
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(input, pair, "two numbers separated by `,`"))?;
+
+    Ok((common::parse(input, x, "a number")?, common::parse(input, y, "a number")?))
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    width: usize,
+    height: usize,
+    robots: Vec<Robot>,
+}
+
+impl Map {
+    pub fn find_robots_in_quads(&self) -> (u32, u32, u32, u32) {
+        let mut q1 = 0;
+        let mut q2 = 0;
+        let mut q3 = 0;
+        let mut q4 = 0;
+
+        // we dont count things directly on the axis
+        let q1_bound = ((0., (self.width / 2) as f32), (0., (self.height / 2) as f32));
+        let q2_bound = (((self.width as f32 / 2.), self.width as f32), (0., (self.height / 2) as f32));
+        let q3_bound = ((0., (self.width / 2) as f32), ((self.height as f32 / 2.), self.height as f32));
+        let q4_bound = (((self.width as f32 / 2.), self.width as f32), ((self.height as f32 / 2.), self.height as f32));
+
+        let bounds = [q1_bound, q2_bound, q3_bound, q4_bound];
+
+        for robot in &self.robots {
+            for (i, bound) in bounds.iter().enumerate() {
+                if (robot.x as f32) >= bound.0.0
+                    && (robot.x as f32) < bound.0.1
+                    && (robot.y as f32) >= bound.1.0
+                    && (robot.y as f32) < bound.1.1
+                {
+                    match i {
+                        0 => q1 += 1,
+                        1 => q2 += 1,
+                        2 => q3 += 1,
+                        3 => q4 += 1,
+                        _ => (),
+                    }
+                }
+            }
+        }
+
+        (q1, q2, q3, q4)
+    }
+
+    pub fn run_second(&mut self) {
+        for robot in &mut self.robots {
+            robot.x += robot.dx;
+            robot.y += robot.dy;
+
+            if robot.x >= self.width as i32 {
+                robot.x -= self.width as i32;
+            }
+            if robot.x < 0 {
+                robot.x += self.width as i32;
+            }
+            if robot.y >= self.height as i32 {
+                robot.y -= self.height as i32;
+            }
+            if robot.y < 0 {
+                robot.y += self.height as i32;
+            }
+        }
+    }
+
+    pub fn parse_input(input: &str, bounds: (usize, usize)) -> Result<Self, ParseError> {
+        let robots = input
+            .lines()
+            .map(|line| Robot::parse_input(input, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            width: bounds.0,
+            height: bounds.1,
+            robots,
+        })
+    }
+
+    fn record(&self, recorder: &mut Recorder) {
+        let robots = || {
+            let mut robots = Grid::new(self.width, self.height, false);
+            for robot in &self.robots {
+                robots[Point::new(robot.x as isize, robot.y as isize)] = true;
+            }
+            robots
+        };
+        recorder.push_with(robots, |_, &robot| if robot { recorder::GREEN } else { recorder::BLACK });
+    }
+
+    pub fn print(&self) {
+        let mut map = vec![vec!['.'; self.width]; self.height];
+
+        for robot in &self.robots {
+            if map[robot.y as usize][robot.x as usize] == '.' {
+                map[robot.y as usize][robot.x as usize] = '1';
+            } else {
+                map[robot.y as usize][robot.x as usize] = map[robot.y as usize][robot.x as usize]
+                    .to_digit(10)
+                    .unwrap()
+                    .wrapping_add(1)
+                    .to_string()
+                    .chars()
+                    .next()
+                    .unwrap();
+            }
+        }
+
+        for row in map {
+            for cell in row {
+                eprint!("{}", cell);
+            }
+            eprintln!();
+        }
+    }
+}
+
+const BOUNDS: (usize, usize) = (101, 103);
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Map::parse_input(input, BOUNDS).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u32, ParseError> {
+    safety_factor(input, BOUNDS, 100)
+}
+
+// the product of the robots in each quadrant after `seconds`
+fn safety_factor(input: &str, bounds: (usize, usize), seconds: usize) -> Result<u32, ParseError> {
+    let mut map = Map::parse_input(input, bounds)?;
+
+    for _ in 0..seconds {
+        map.run_second();
+    }
+
+    let (q1, q2, q3, q4) = map.find_robots_in_quads();
+    Ok(q1 * q2 * q3 * q4)
+}
+
+pub fn part_2(input: &str) -> Result<i32, ParseError> {
+    find_tree(input, None)
+}
+
+// the robots are bunched up into the tree when they're least spread over the quadrants
+fn find_tree(input: &str, mut recorder: Option<&mut Recorder>) -> Result<i32, ParseError> {
+    let mut map = Map::parse_input(input, BOUNDS)?;
+
+    let seconds = 8000;
+    let mut lowest = (u32::MAX, -1);
+
+    for i in 0..seconds {
+        map.run_second();
+        if let Some(recorder) = recorder.as_deref_mut() {
+            map.record(recorder);
+        }
+        let (q1, q2, q3, q4) = map.find_robots_in_quads();
+        let safety = q1 * q2 * q3 * q4;
+        if safety < lowest.0 {
+            lowest = (safety, i);
+            if common::verbosity() >= 1 {
+                map.print();
+                eprintln!("seconds: {}", i+1);
+            }
+        }
+    }
+
+    Ok(lowest.1 + 1)
+}
+
+// records the robots moving, one frame per second, through all the seconds part 2 searches for
+// the tree. Both parts move the robots the same way.
+pub fn record(input: &str, _part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+    find_tree(input, Some(recorder)).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // the example room is 11x7, and the tree only shows up in the full-size room
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::safety_factor(EXAMPLE, (11, 7), 100).unwrap(), 12);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,300 @@
+use common::ParseError;
+use grid::{Dir, Grid, Point};
+use recorder::Recorder;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+    Box(bool), // false for left, true for right
+    NarrowBox,
+    Robot,
+}
+
+#[derive(Clone)]
+struct Map {
+    tiles: Grid<Tile>,
+    moves: Vec<Dir>,
+    robot_pos: Point,
+}
+
+impl Map {
+    pub fn new(tiles: Grid<Tile>, moves: Vec<Dir>, robot_pos: Point) -> Self {
+        Self { tiles, moves, robot_pos }
+    }
+
+    // `wide` doubles the width of everything except the robot
+    pub fn parse_input(input: &str, wide: bool) -> Result<Self, ParseError> {
+        // the map comes first, then the moves after an empty line
+        let (map_input, moves_input) = input.split_once("\n\n").unwrap_or((input, ""));
+
+        let mut robot_pos = None;
+        let mut tiles = Grid::parse(map_input, |p, c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Empty),
+            'O' => Some(Tile::NarrowBox),
+            '@' => {
+                robot_pos = Some(p);
+                Some(Tile::Robot)
+            }
+            _ => None,
+        }, "one of `#`, `.`, `O` or `@`")?;
+
+        let mut robot_pos = robot_pos.ok_or_else(|| ParseError::missing(input, "a robot `@` in the map"))?;
+
+        if wide {
+            let narrow = tiles;
+            tiles = Grid::from_fn(narrow.width() * 2, narrow.height(), |p| {
+                let left = p.x % 2 == 0;
+                match narrow[Point::new(p.x / 2, p.y)] {
+                    Tile::NarrowBox => Tile::Box(!left),
+                    Tile::Robot if !left => Tile::Empty,
+                    tile => tile,
+                }
+            });
+            robot_pos.x *= 2;
+        }
+
+        let mut moves = Vec::new();
+        for line in moves_input.lines() {
+            for (i, c) in line.char_indices() {
+                moves.push(match c {
+                    '^' => Dir::Up,
+                    'v' => Dir::Down,
+                    '<' => Dir::Left,
+                    '>' => Dir::Right,
+                    _ => return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "one of `^`, `v`, `<` or `>`")),
+                });
+            }
+        }
+
+        Ok(Self::new(tiles, moves, robot_pos))
+    }
+
+    pub fn print(&self) {
+        eprint!("{}", self.tiles.render(|_, tile| match tile {
+            Tile::Wall => '#',
+            Tile::Empty => '.',
+            Tile::Box(side) => {
+                if *side {
+                    ']'
+                } else {
+                    '['
+                }
+            }
+            Tile::NarrowBox => 'O',
+            Tile::Robot => '@',
+        }));
+
+        /*
+        for m in &self.moves {
+            match m {
+                Dir::Up => print!("^"),
+                Dir::Down => print!("v"),
+                Dir::Left => print!("<"),
+                Dir::Right => print!(">"),
+            }
+        }
+        println!();
+        */
+    }
+
+    fn move_recursive(&mut self, pos: Point, dir: Dir, actually_move: bool) -> bool {
+        // off the edge of the map is as good as a wall
+        let Some(&current_type) = self.tiles.get(pos) else {
+            return false;
+        };
+        
+        //println!("current_type: {:?}", current_type);
+
+        if let Tile::Box(side) = current_type {
+            let (left, right) = if side {
+                (pos.step(Dir::Left), pos)
+            } else {
+                (pos, pos.step(Dir::Right))
+            };
+
+            let next_left = left.step(dir);
+            let next_right = right.step(dir);
+
+            let going_left = dir == Dir::Left;
+            let going_right = dir == Dir::Right;
+
+            let left_success = if going_right { true } else {
+                self.move_recursive(next_left, dir, false)
+            };
+
+            let right_success = if going_left { true } else {                
+                self.move_recursive(next_right, dir, false)
+            };
+
+            if left_success && right_success {
+                if actually_move {
+                    if going_right {
+                        self.tiles[next_left] = Tile::Box(false);
+                        // recurse
+                        self.move_recursive(next_right, dir, true);
+                        self.tiles[next_right] = Tile::Box(true);
+
+                        // reset
+                        self.tiles[left] = Tile::Empty;
+                    }
+                    else if going_left {
+                        self.tiles[next_right] = Tile::Box(true);
+                        // recurse
+                        self.move_recursive(next_left, dir, true);
+                        self.tiles[next_left] = Tile::Box(false);
+
+                        // reset
+                        self.tiles[right] = Tile::Empty;
+                    }
+                    else {
+                        // recurse
+                        self.move_recursive(next_left, dir, true);
+                        self.move_recursive(next_right, dir, true);
+
+                        self.tiles[next_left] = Tile::Box(false);
+                        self.tiles[next_right] = Tile::Box(true);
+
+                        // reset
+                        self.tiles[left] = Tile::Empty;
+                        self.tiles[right] = Tile::Empty;
+                    }
+                }
+
+                return true;
+            }
+
+            return false;
+        }
+
+        if current_type == Tile::Wall {
+            return false;
+        }
+
+        if current_type == Tile::Empty {
+            return true;
+        }
+
+        if current_type == Tile::Robot || current_type == Tile::NarrowBox {
+            // recurse
+            let next_pos = pos.step(dir);
+            let can = self.move_recursive(next_pos, dir, actually_move);
+
+            if can && actually_move {
+                self.tiles[next_pos] = current_type;
+                self.tiles[pos] = Tile::Empty;
+            }
+
+            return can;
+        }
+
+        false
+    }
+
+    pub fn run_turn(&mut self) -> bool {
+        if self.moves.is_empty() {
+            return false;
+        }
+
+        let next_move = self.moves.remove(0);
+
+        //println!("Next move: {:?}", next_move);
+
+        if self.move_recursive(self.robot_pos, next_move, true) {
+            self.robot_pos = self.robot_pos.step(next_move);
+        }
+
+        true
+    }
+
+    fn record(&self, recorder: &mut Recorder) {
+        recorder.push(&self.tiles, |_, tile| match tile {
+            Tile::Wall => recorder::GREY,
+            Tile::Empty => recorder::BLACK,
+            Tile::Box(_) | Tile::NarrowBox => recorder::BROWN,
+            Tile::Robot => recorder::RED,
+        });
+    }
+
+    fn find_gps(&self, pos: Point) -> usize {
+        pos.y as usize * 100 + pos.x as usize
+    }
+
+    pub fn gps_sum(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Box(false) || tile == Tile::NarrowBox)
+            .map(|(pos, _)| self.find_gps(pos))
+            .sum()
+    }
+}
+
+fn run(input: &str, wide: bool, mut recorder: Option<&mut Recorder>) -> Result<usize, ParseError> {
+    // parse input
+    let mut map = Map::parse_input(input, wide)?;
+
+    if common::verbosity() >= 1 {
+        map.print();
+    }
+
+    // run turns
+    loop {
+        if let Some(recorder) = recorder.as_deref_mut() {
+            map.record(recorder);
+        }
+        if !map.run_turn() {
+            break;
+        }
+    }
+
+    if common::verbosity() >= 1 {
+        map.print();
+    }
+
+    Ok(map.gps_sum())
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Map::parse_input(input, false).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    run(input, false, None)
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    run(input, true, None)
+}
+
+// records the robot pushing boxes around, one frame per move. Part 2 is the wide warehouse.
+pub fn record(input: &str, part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+    run(input, part == 2, Some(recorder)).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 10092);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 9021);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
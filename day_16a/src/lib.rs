@@ -0,0 +1,217 @@
+#![allow(dead_code)]
+
+use std::collections::{HashMap, HashSet};
+
+use common::ParseError;
+use grid::{Dir, Grid, Point};
+use recorder::Recorder;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Wall,
+    End,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Reindeer {
+    pos: Point,
+    direction: Dir,
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    data: Grid<Tile>,
+}
+
+impl Map {
+    pub fn new(data: Grid<Tile>) -> Self {
+        Map { data }
+    }
+
+    pub fn from_string(input: &str) -> Result<(Self, Reindeer), ParseError> {
+        let mut reindeer = None;
+
+        let map = Grid::parse(input, |p, c| match c {
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            'S' => {
+                reindeer = Some(Reindeer {
+                    pos: p,
+                    direction: Dir::Right,
+                });
+                Some(Tile::Empty)
+            },
+            'E' => Some(Tile::End),
+            _ => None,
+        }, "one of `.`, `#`, `S` or `E`")?;
+
+        let reindeer = reindeer.ok_or_else(|| ParseError::missing(input, "a start `S` in the map"))?;
+
+        Ok((Map::new(map), reindeer))
+    }
+
+    fn print(&self) {
+        eprint!("{}", self.data.render(|_, tile| match tile {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::End => 'E',
+        }));
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Map::from_string(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let (map, reindeer) = Map::from_string(input)?;
+
+    Ok(find_path(&map, reindeer, None).0)
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let (map, reindeer) = Map::from_string(input)?;
+
+    // find the path
+    Ok(find_path(&map, reindeer, None).1)
+}
+
+// returns the lowest score and how many tiles are on any of the lowest scoring paths
+fn find_path(map: &Map, reindeer: Reindeer, mut recorder: Option<&mut Recorder>) -> (i64, i64) {
+    let mut queue = std::collections::VecDeque::new();
+    let mut visited = HashMap::new();
+
+    queue.push_back((reindeer, 0, vec![ reindeer.pos ]));
+
+    let mut paths = Vec::new();
+
+    let mut all_used_tiles = HashSet::new();
+
+    let mut shortest_path = i64::MAX;
+
+    while let Some((reindeer, score, prev)) = queue.pop_front() {
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.push(&map.data, |p, tile| match tile {
+                _ if p == reindeer.pos => recorder::RED,
+                Tile::Wall => recorder::GREY,
+                Tile::End => recorder::YELLOW,
+                Tile::Empty if Dir::ALL.iter().any(|&dir| visited.contains_key(&(p, dir))) => recorder::BLUE,
+                Tile::Empty => recorder::BLACK,
+            });
+        }
+
+        match map.data[reindeer.pos] {
+            Tile::Empty => {
+                let left = reindeer.direction.turn_left();
+                let right = reindeer.direction.turn_right();
+
+                for dir in [reindeer.direction, left, right] {
+                    // turning happens on the spot
+                    let pos = if dir == reindeer.direction { reindeer.pos.step(dir) } else { reindeer.pos };
+                    let mut prev = prev.clone();
+
+                    if !map.data.contains(pos) {
+                        continue;
+                    }
+
+                    let new_score = if dir == reindeer.direction {
+                        score+1
+                    } else {
+                        score + 1000
+                    };
+
+                    if !visited.contains_key(&(pos, dir)) || *visited.get(&(pos, dir)).unwrap() >= new_score {
+                        visited.insert((pos, dir), new_score);
+
+                        prev.push(pos);
+
+                        queue.push_back((Reindeer { pos, direction: dir }, new_score, prev));
+                    }
+                }
+
+            },
+            Tile::End => {
+                if score <= shortest_path {
+                    paths.push((score, prev));
+                }
+                
+                if score < shortest_path {
+                    shortest_path = score;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let shortest_paths = paths.iter().filter(|(score, _)| *score == shortest_path).collect::<Vec<_>>();
+
+    for (_, path) in shortest_paths {
+        all_used_tiles.extend(path.iter().copied());
+    }
+
+    if common::verbosity() >= 1 {
+        display_current_state(map, &all_used_tiles);
+    }
+
+    // the last frame shows every tile on a best path
+    if let Some(recorder) = recorder {
+        recorder.push(&map.data, |p, tile| match tile {
+            _ if all_used_tiles.contains(&p) => recorder::GREEN,
+            Tile::Wall => recorder::GREY,
+            Tile::End => recorder::YELLOW,
+            Tile::Empty => recorder::BLACK,
+        });
+    }
+
+    (shortest_path, all_used_tiles.len() as i64)
+}
+
+// records the search spreading out from the start, one frame per step, then the best paths.
+// Both parts run the same search.
+pub fn record(input: &str, _part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+    let (map, reindeer) = Map::from_string(input)?;
+    find_path(&map, reindeer, Some(recorder));
+    Ok(())
+}
+
+fn display_current_state(map: &Map, used_tiles: &HashSet<Point>) {
+    eprintln!("{}", map.data.render(|p, tile| {
+        if used_tiles.contains(&p) {
+            'O'
+        } else {
+            match tile {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+                Tile::End => 'E',
+            }
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 7036);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 45);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,308 @@
+use common::{next_field, ParseError};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Adv(Combo),
+    Bxl(Literal),
+    Bst(Combo),
+    Jnz(Literal),
+    Bxc(Literal),
+    Out(Combo),
+    Bdv(Combo),
+    Cdv(Combo),
+}
+
+impl Instruction {
+    // `None` for an unknown opcode or a combo operand of 7
+    pub fn from_tuple(t: (u8, u8)) -> Option<Self> {
+        Some(match t.0 {
+            0 => Self::Adv(Combo::from_u8(t.1)?),
+            1 => Self::Bxl(t.1),
+            2 => Self::Bst(Combo::from_u8(t.1)?),
+            3 => Self::Jnz(t.1),
+            4 => Self::Bxc(t.1),
+            5 => Self::Out(Combo::from_u8(t.1)?),
+            6 => Self::Bdv(Combo::from_u8(t.1)?),
+            7 => Self::Cdv(Combo::from_u8(t.1)?),
+            _ => return None,
+        })
+    }
+
+    pub fn to_num(&self) -> (u8, u8) {
+        match self {
+            Instruction::Adv(com) => (0, com.to_num()),
+            Instruction::Bxl(lit) => (1, *lit),
+            Instruction::Bst(com) => (2, com.to_num()),
+            Instruction::Jnz(lit) => (3, *lit),
+            Instruction::Bxc(l) => (4, *l), // hardcode ftw
+            Instruction::Out(com) => (5, com.to_num()),
+            Instruction::Bdv(com) => (6, com.to_num()),
+            Instruction::Cdv(com) => (7, com.to_num()),
+        }
+    }
+}
+
+type Literal = u8;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Combo {
+    Literal(u8),
+    RegA,
+    RegB,
+    RegC
+}
+
+impl Combo {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        match n {
+            0..=3 => Some(Combo::Literal(n)),
+            4 => Some(Combo::RegA),
+            5 => Some(Combo::RegB),
+            6 => Some(Combo::RegC),
+            _ => None,
+        }
+    }
+
+    pub fn to_num(&self) -> u8 {
+        match self {
+            Combo::Literal(n) => *n,
+            Combo::RegA => 4,
+            Combo::RegB => 5,
+            Combo::RegC => 6,
+        }
+    }
+}
+
+// reads a line like `Register A: 729`
+fn register<'a>(input: &str, lines: &mut impl Iterator<Item = &'a str>, prefix: &str) -> Result<u64, ParseError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::missing(input, format!("a line starting with `{}`", prefix)))?;
+    let value = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, line, format!("a line starting with `{}`", prefix)))?;
+
+    common::parse(input, value, "a number")
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub ip: usize,
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+}
+
+impl Computer {
+    pub fn new(
+        reg_a: u64,
+        reg_b: u64,
+        reg_c: u64,
+    ) -> Self {
+        Self {
+            ip: 0,
+            reg_a,
+            reg_b,
+            reg_c,
+        }
+    }
+
+    pub fn from_input(input: &str) -> Result<(Self, Vec<Instruction>), ParseError> {
+        let mut lines = input.lines();
+
+        let reg_a = register(input, &mut lines, "Register A: ")?;
+        let reg_b = register(input, &mut lines, "Register B: ")?;
+        let reg_c = register(input, &mut lines, "Register C: ")?;
+        let blank = next_field(input, input.trim_end(), &mut lines, "an empty line")?;
+        let program_line = next_field(input, blank, &mut lines, "a `Program` line")?;
+        let program_line = program_line
+            .strip_prefix("Program: ")
+            .ok_or_else(|| ParseError::at(input, program_line, "a line starting with `Program: `"))?;
+
+        let numbers = program_line.split(',').collect::<Vec<_>>();
+        let pairs = numbers.chunks_exact(2);
+        if let [last] = pairs.remainder() {
+            return Err(ParseError::after(input, last, "an operand"));
+        }
+
+        let program = pairs.map(|pair| {
+            let opcode = common::parse(input, pair[0], "an opcode")?;
+            let operand = common::parse(input, pair[1], "an operand")?;
+            Instruction::from_tuple((opcode, operand)).ok_or_else(|| {
+                ParseError::at(input, pair[0], "an opcode from 0 to 7 followed by a valid operand")
+            })
+        }).collect::<Result<_, _>>()?;
+
+        Ok((Self::new(reg_a, reg_b, reg_c), program))
+    }
+
+    pub fn run(&mut self, a: u64, instructions: &[Instruction]) -> Vec<u8> {
+        self.reg_a = a;
+        self.reg_b = 0;
+        self.reg_c = 0;
+
+        self.execute(instructions)
+    }
+
+    // runs from the registers as they are now
+    pub fn execute(&mut self, instructions: &[Instruction]) -> Vec<u8> {
+        let mut output = Vec::new();
+        self.ip = 0;
+
+        while let Some(instruction) = instructions.get(self.ip) {
+            match instruction {
+                Instruction::Adv(com) => {
+                    let denom = 2_u64.pow(self.execute_combo(com) as u32);
+                    self.reg_a /= denom;
+                }
+                Instruction::Bxl(lit) => {
+                    self.reg_b ^= *lit as u64;
+                }
+                Instruction::Bst(com) => {
+                    self.reg_b = self.execute_combo(com) % 8;
+                }
+                Instruction::Jnz(lit) => {
+                    if self.reg_a != 0 {
+                        self.ip = *lit as usize;
+                        continue;
+                    }
+                }
+                Instruction::Bxc(_) => {
+                    self.reg_b ^= self.reg_c;
+                }
+                Instruction::Out(com) => {
+                    output.push(self.execute_combo(com) as u8 % 8);
+                }
+                Instruction::Bdv(com) => {
+                    let denom = 2_u64.pow(self.execute_combo(com) as u32);
+                    self.reg_b = self.reg_a / denom;
+                }
+                Instruction::Cdv(com) => {
+                    let denom = 2_u64.pow(self.execute_combo(com) as u32);
+                    self.reg_c = self.reg_a / denom;
+                }
+            }
+            self.ip += 1;
+        }
+
+        output
+    }
+
+    fn execute_combo(&self, combo: &Combo) -> u64 {
+        match combo {
+            Combo::Literal(l) => *l as u64,
+            Combo::RegA => self.reg_a,
+            Combo::RegB => self.reg_b,
+            Combo::RegC => self.reg_c
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Computer::from_input(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<String, ParseError> {
+    let (mut computer, instructions) = Computer::from_input(input)?;
+
+    Ok(computer
+        .execute(&instructions)
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    let (mut computer, instructions) = Computer::from_input(input)?;
+    let instrs_as_out = instructions.iter().map(|i|i.to_num()).flat_map(|(a, b)| [a, b]).collect::<Vec<_>>();
+
+    lowest_a(&mut computer, &instructions, &instrs_as_out)
+        .ok_or_else(|| ParseError::missing(input, "a program that can output itself"))
+}
+
+// the lowest `a` that makes the program output `target`.
+// this only works for programs like the puzzle's, which output something from the lowest ten
+// bits of `a` and then shift it down three bits, so `a` can be found three bits at a time
+fn lowest_a(computer: &mut Computer, instructions: &[Instruction], target: &[u8]) -> Option<u64> {
+    // everything the program has output so far matches the target
+    let mut matches = |a: u64, outputs: usize| {
+        let out = computer.run(a, instructions);
+        out.iter().zip(target).take(outputs).all(|(a, b)| a == b)
+    };
+
+    let mut valid = (0..1024_u64).filter(|&a| matches(a, 1)).collect::<Vec<_>>();
+
+    let mut bit_length = 10;
+    for num_correct in 2..=target.len() {
+        let mut next_valid = Vec::new();
+        for &a in &valid {
+            for d_a in 0..8 {
+                let a = a + (d_a << bit_length);
+                if matches(a, num_correct) {
+                    next_valid.push(a);
+                }
+            }
+        }
+        valid = next_valid;
+        bit_length += 3;
+    }
+
+    valid.into_iter().filter(|&a| computer.run(a, instructions) == target).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{Computer, Instruction};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE_2).unwrap(), 117440);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    // shaped like the puzzle's programs: mix the low three bits of `a` with some higher ones,
+    // output them, then shift `a` down three bits until it's 0
+    fn program(x: u8, y: u8) -> Vec<Instruction> {
+        [2, 4, 1, x, 7, 5, 0, 3, 1, y, 4, 1, 5, 5, 3, 0]
+            .chunks(2)
+            .map(|pair| Instruction::from_tuple((pair[0], pair[1])).unwrap())
+            .collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        // counts up from 0 until something outputs the same thing as `a`
+        #[test]
+        fn lowest_a_matches_naive(x in 0_u8..8, y in 0_u8..8, a in 1_u64..1 << 18) {
+            let instructions = program(x, y);
+            let mut computer = Computer::new(0, 0, 0);
+            let target = computer.run(a, &instructions);
+
+            let naive = (0..=a).find(|&a| computer.run(a, &instructions) == target);
+            prop_assert_eq!(super::lowest_a(&mut computer, &instructions, &target), naive);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use common::{next_field, ParseError};
+use grid::{Grid, Point};
+
+const SIZE: (usize, usize) = (71, 71);
+
+fn parse_point(input: &str, line: &str) -> Result<Point, ParseError> {
+    let mut l = line.split(',');
+    let x = common::parse(input, next_field(input, line, &mut l, "an x coordinate")?, "an x coordinate")?;
+    let y = common::parse(input, next_field(input, line, &mut l, "`,` and a y coordinate")?, "a y coordinate")?;
+    Ok(Point::new(x, y))
+}
+
+fn get_bytes(input: &str, size: (usize, usize)) -> Result<Vec<Point>, ParseError> {
+    let space = Grid::new(size.0, size.1, ());
+
+    input
+        .lines()
+        .map(|line| {
+            let point = parse_point(input, line)?;
+            if !space.contains(point) {
+                return Err(ParseError::at(input, line, format!("a byte inside the {}x{} memory space", size.0, size.1)));
+            }
+            Ok(point)
+        })
+        .collect()
+}
+
+struct Map {
+    corrupted: Grid<bool>
+}
+
+impl Map {
+    pub fn new(size: (usize, usize), o: impl Iterator<Item = Point>) -> Self {
+        let mut corrupted = Grid::new(size.0, size.1, false);
+        for p in o {
+            corrupted[p] = true;
+        }
+
+        Self {
+            corrupted
+        }
+    }
+
+    pub fn can_move_to_point(&self, p: Point) -> bool {
+        self.corrupted.get(p) == Some(&false)
+    }
+}
+
+#[inline]
+fn min(a: Option<u64>, b: u64) -> u64 {
+    if let Some(a) = a {
+        if a < b { a } else { b }
+    } else { b }
+}
+
+fn find_easist_path<O: std::iter::Iterator<Item = Point>>(size: (usize, usize), obstacles: O) -> Option<u64> {
+    let map = Map::new(size, obstacles);
+
+    let mut queue = std::collections::VecDeque::new();
+    let mut visited = HashMap::new();
+
+    let start = Point::new(0, 0);
+    queue.push_back((start, 0));
+    visited.insert(start, 0);
+
+    let mut shortest_path = None;
+
+    let end = Point::new(size.0 as isize - 1, size.1 as isize - 1);
+    while let Some((pos, score)) = queue.pop_front() {
+
+        if pos == end {
+            shortest_path = Some(min(shortest_path, score));
+            continue;
+        }
+
+        let new_score = score+1;
+        for next in pos.neighbours() {
+            if can_do_point(&map, &visited, next, new_score) {
+                queue.push_back((next, new_score));
+                visited.insert(next, new_score);
+            }
+        }
+    }
+
+    shortest_path
+}
+
+fn can_do_point(map: &Map, visited: &HashMap<Point, u64>, point: Point, score: u64) -> bool {
+    if !map.can_move_to_point(point) {
+        return false;
+    }
+    
+    if let Some(&prev) = visited.get(&point) {
+        prev > score
+    } else {
+        true
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    get_bytes(input, SIZE).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    shortest_path(input, SIZE, 1024)
+}
+
+pub fn part_2(input: &str) -> Result<String, ParseError> {
+    first_blocking_byte(input, SIZE)
+}
+
+// the shortest way out once the first `fallen` bytes have landed
+fn shortest_path(input: &str, size: (usize, usize), fallen: usize) -> Result<u64, ParseError> {
+    let bytes = get_bytes(input, size)?;
+
+    find_easist_path(size, bytes.iter().take(fallen).copied())
+        .ok_or_else(|| ParseError::missing(input, format!("a way out once the first {} bytes have fallen", fallen)))
+}
+
+fn first_blocking_byte(input: &str, size: (usize, usize)) -> Result<String, ParseError> {
+    let bytes = get_bytes(input, size)?;
+    let blocked = |fallen: usize| find_easist_path(size, bytes.iter().take(fallen).copied()).is_none();
+
+    if bytes.is_empty() {
+        return Err(ParseError::missing(input, "at least one byte"));
+    }
+    if !blocked(bytes.len()) {
+        return Err(ParseError::missing(input, "a byte that cuts off the way out"));
+    }
+
+    // nothing has fallen at `min` and the way is still open, and it's blocked by the time `max`
+    // bytes have, so the byte that blocks it is the last of the fewest that do
+    let (mut min, mut max) = (0, bytes.len());
+    while max - min > 1 {
+        let mid = (min + max) / 2;
+        if blocked(mid) {
+            max = mid;
+        } else {
+            min = mid;
+        }
+    }
+
+    let breaker = bytes[max - 1];
+    Ok(format!("{},{}", breaker.x, breaker.y))
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::shortest_path(EXAMPLE, (7, 7), 12).unwrap(), 22);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::first_blocking_byte(EXAMPLE, (7, 7)).unwrap(), "6,1");
+    }
+
+    #[test]
+    fn no_way_out() {
+        assert!(super::shortest_path("1,0\n0,1\n", (7, 7), 2).is_err());
+        assert!(super::first_blocking_byte("", (7, 7)).is_err());
+        assert!(super::first_blocking_byte("1,0\n", (7, 7)).is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use common::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Color {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl Color {
+    fn from_char(s: char) -> Option<Self> {
+        match s {
+            'w' => Some(Color::White),
+            'u' => Some(Color::Blue),
+            'b' => Some(Color::Black),
+            'r' => Some(Color::Red),
+            'g' => Some(Color::Green),
+            _ => None,
+        }
+    }
+}
+
+// the colors of a stripe, `s` being a slice of `input`
+fn parse_colors(input: &str, s: &str) -> Result<Vec<Color>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| {
+            Color::from_char(c)
+                .ok_or_else(|| ParseError::at(input, &s[i..i + c.len_utf8()], "one of `w`, `u`, `b`, `r` or `g`"))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Towel {
+    colors: Vec<Color>,
+}
+
+impl Towel {
+    fn from_str(input: &str, s: &str) -> Result<Self, ParseError> {
+        let colors = parse_colors(input, s)?;
+        Ok(Towel { colors })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Pattern {
+    pub colors: Vec<Color>,
+}
+
+impl Pattern {
+    fn from_str(input: &str, s: &str) -> Result<Self, ParseError> {
+        let colors = parse_colors(input, s)?;
+        Ok(Pattern { colors })
+    }
+}
+
+struct Cache<T: Copy> {
+    pub cache: HashMap<Pattern, T>,
+}
+
+impl<T: Copy> Cache<T> {
+    fn new() -> Self {
+        Cache {
+            cache: HashMap::new(),
+        }
+    }
+
+    fn get(&self, pattern: &Pattern) -> Option<T> {
+        self.cache.get(pattern).copied()
+    }
+
+    fn insert(&mut self, pattern: Pattern, result: T) {
+        self.cache.insert(pattern, result);
+    }
+}
+
+fn try_wrapper(pattern: &Pattern, towels: &Vec<Towel>, level: u64, cache: &mut Cache<u64>) -> u64 {
+    if cache.get(pattern).is_some() {
+        return cache.get(pattern).unwrap();
+    }
+
+    let result = try_pattern(pattern, towels, level, cache);
+
+    cache.insert(pattern.clone(), result);
+
+    result
+}
+
+fn try_pattern(pattern: &Pattern, towels: &Vec<Towel>, level: u64, cache: &mut Cache<u64>) -> u64 {
+    if pattern.colors.is_empty() {
+        return 1;
+    }
+
+    let mut total = 0;
+
+    for towel in towels.iter() {
+        // check if the towel equals the start of the pattern
+        if towel.colors.len() > pattern.colors.len() {
+            continue;
+        }
+
+        let mut found = true;
+        for i in 0..towel.colors.len() {
+            if towel.colors[i] != pattern.colors[i] {
+                found = false;
+                break;
+            }
+        }
+
+        if found {
+            // remove the first colors from the pattern
+            let mut copy = pattern.clone();
+            copy.colors = copy.colors.split_off(towel.colors.len());
+            total += try_wrapper(&copy, towels, level+1, cache);
+        }
+    }
+
+    total
+}
+
+fn parse_towels_and_patterns(input: &str) -> Result<(Vec<Towel>, Vec<Pattern>), ParseError> {
+    let mut lines = input.lines();
+
+    // first line is towels
+    let towels = lines.next().ok_or_else(|| ParseError::missing(input, "a list of towels"))?;
+    let towels = towels
+        .split(", ")
+        .map(|towel| Towel::from_str(input, towel))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // skip empty line
+    lines.next();
+
+    // all the next lines are patterns
+    let patterns = lines
+        .map(|line| Pattern::from_str(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((towels, patterns))
+}
+
+// the number of ways each pattern can be made
+fn arrangements(input: &str) -> Result<Vec<u64>, ParseError> {
+    let (towels, patterns) = parse_towels_and_patterns(input)?;
+
+    let mut ways = Vec::with_capacity(patterns.len());
+    let mut cache = Cache::new();
+    for (i, pattern) in patterns.iter().enumerate() {
+        ways.push(try_pattern(pattern, &towels, 0, &mut cache));
+        common::diag!(2, "Pattern {} checked", i);
+    }
+
+    Ok(ways)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_towels_and_patterns(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    Ok(arrangements(input)?.into_iter().filter(|&ways| ways > 0).count())
+}
+
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    Ok(arrangements(input)?.into_iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 6);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 16);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use common::ParseError;
+
+fn parse_line(input: &str, line: &str, width: Option<usize>) -> Result<Vec<i64>, ParseError> {
+    // int   int   int ...
+    let mut ids = Vec::with_capacity(width.unwrap_or(2));
+    for field in line.split_whitespace() {
+        if Some(ids.len()) == width {
+            return Err(ParseError::at(input, field, "the end of the line"));
+        }
+        ids.push(common::parse(input, field, "a location id")?);
+    }
+
+    // the first line decides how many columns there are, and it takes two to compare anything
+    if ids.len() < width.unwrap_or(2) {
+        return Err(ParseError::after(input, line, "another location id"));
+    }
+
+    Ok(ids)
+}
+
+/// Reads every column of location ids, each one sorted. Every line has to have as many ids as
+/// the first, and there have to be at least two.
+pub fn parse_columns(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+
+    for line in input.lines() {
+        let width = if columns.is_empty() { None } else { Some(columns.len()) };
+        let ids = parse_line(input, line, width)?;
+
+        if columns.is_empty() {
+            columns = vec![Vec::new(); ids.len()];
+        }
+        for (column, id) in columns.iter_mut().zip(ids) {
+            column.push(id);
+        }
+    }
+
+    // an empty list still has a left and a right
+    if columns.is_empty() {
+        columns = vec![Vec::new(); 2];
+    }
+
+    for column in &mut columns {
+        column.sort();
+    }
+
+    Ok(columns)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_columns(input).map(|_| ())
+}
+
+/// The sum of how far apart the ids are once both sorted columns are paired up smallest first.
+pub fn total_distance(left: &[i64], right: &[i64]) -> i64 {
+    left.iter()
+        .zip(right.iter())
+        .map(|(a, b)| (a - b).abs())
+        .sum()
+}
+
+/// Every left id times the number of times it shows up on the right. Both columns have to be
+/// sorted.
+pub fn similarity(left: &[i64], right: &[i64]) -> i64 {
+    // equal ids sit together in runs, and a run on the left scores once per id in the matching
+    // run on the right
+    let mut score = 0;
+    let (mut l, mut r) = (0, 0);
+
+    while l < left.len() && r < right.len() {
+        match left[l].cmp(&right[r]) {
+            Ordering::Less => l += 1,
+            Ordering::Greater => r += 1,
+            Ordering::Equal => {
+                let item = left[l];
+                let left_run = left[l..].iter().take_while(|&&x| x == item).count();
+                let right_run = right[r..].iter().take_while(|&&x| x == item).count();
+
+                score += item * (left_run * right_run) as i64;
+                l += left_run;
+                r += right_run;
+            }
+        }
+    }
+
+    score
+}
+
+/// How one column compares with another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub distance: i64,
+    pub similarity: i64,
+}
+
+/// Compares column `a` (the left) with column `b` (the right), both counted from 0. `None` if
+/// either column doesn't exist.
+pub fn compare(columns: &[Vec<i64>], a: usize, b: usize) -> Option<Comparison> {
+    let (left, right) = (columns.get(a)?, columns.get(b)?);
+    Some(Comparison {
+        distance: total_distance(left, right),
+        similarity: similarity(left, right),
+    })
+}
+
+/// Every column compared with every other, indexed `[left][right]`. Distance is the same either
+/// way round but similarity isn't, so the whole square is filled in.
+pub fn matrix(columns: &[Vec<i64>]) -> Vec<Vec<Comparison>> {
+    (0..columns.len())
+        .map(|a| (0..columns.len()).filter_map(|b| compare(columns, a, b)).collect())
+        .collect()
+}
+
+/// A table of the distances and one of the similarity scores between every pair of columns,
+/// with the columns numbered from 1 and the left column down the side.
+pub fn report(input: &str) -> Result<String, ParseError> {
+    let columns = parse_columns(input)?;
+    let matrix = matrix(&columns);
+
+    let table = |title: &str, value: fn(&Comparison) -> i64| {
+        let cells: Vec<Vec<String>> = matrix
+            .iter()
+            .map(|row| row.iter().map(|c| value(c).to_string()).collect())
+            .collect();
+        let width = cells.iter().flatten().map(String::len).max().unwrap_or(0);
+        let side = title.len();
+
+        let mut out = title.to_string();
+        for b in 1..=columns.len() {
+            out += &format!("  {:>width$}", b);
+        }
+        out.push('\n');
+        for (a, row) in cells.iter().enumerate() {
+            out += &format!("{:>side$}", a + 1);
+            for cell in row {
+                out += &format!("  {:>width$}", cell);
+            }
+            out.push('\n');
+        }
+        out
+    };
+
+    Ok(table("distance", |c| c.distance) + "\n" + &table("similarity", |c| c.similarity))
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let columns = parse_columns(input)?;
+    Ok(total_distance(&columns[0], &columns[1]))
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let columns = parse_columns(input)?;
+    Ok(similarity(&columns[0], &columns[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 11);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 31);
+    }
+
+    #[test]
+    fn similarity_of_repeats_and_unmatched_ids() {
+        // 3 is on both sides twice, and 9 is bigger than anything on the right
+        assert_eq!(super::similarity(&[1, 3, 3, 9], &[3, 3, 4]), 12);
+        assert_eq!(super::similarity(&[2, 2], &[]), 0);
+        assert_eq!(super::similarity(&[5], &[1, 5, 5, 5, 8]), 15);
+    }
+
+    #[test]
+    fn matrix_of_three_columns() {
+        let columns = super::parse_columns("1 3 3\n2 3 9000000000\n3 1 1\n").unwrap();
+        let matrix = super::matrix(&columns);
+
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[0][1], super::Comparison { distance: 1, similarity: 7 });
+        assert_eq!(matrix[1][0].distance, 1);
+        assert_eq!(matrix[2][2].similarity, 9000000004);
+        assert!(super::parse_columns("1 2 3\n4 5\n").is_err());
+        assert!(super::parse_columns("1 2\n4 5 6\n").is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
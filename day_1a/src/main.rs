@@ -1,60 +1,32 @@
-fn parse_line(line: &str) -> (i32, i32) {
-    // int   int
-    let mut iter = line.split_whitespace();
-    let a: i32 = iter.next().unwrap().parse().unwrap();
-    let b: i32 = iter.next().unwrap().parse().unwrap();
-    (a, b)
-}
+use common::or_exit;
 
 fn main() {
     // read from input.txt
     let input = std::fs::read_to_string("input.txt").unwrap();
-
-    // split by new line
-    let lines = input.lines();
-
-    // create 2 lists (1 for left, 1 for right)
-    let mut left: Vec<i32> = Vec::new();
-    let mut right: Vec<i32> = Vec::new();
-
-    // loop through each line
-    for line in lines {
-        // parse the line
-        let (a, b) = parse_line(line);
-        // push to the lists
-        left.push(a);
-        right.push(b);
-    }
-
-    // sort the lists
-    left.sort();
-    right.sort();
-    
-    // now we find the similarity score.
-    /* to find this we need to
-    1. loop over the first list
-    2. find the amount of times the number is in the second list
-    3. multiply the item by the amount of times it is in the second list
-    4. add the result to the score
-    */
-
-    let mut score = 0;
-    let mut right_index = 0;
-    for item in left {
-        let mut count = 0; 
-        loop {
-            let right_item = right[right_index];
-
-            if item == right_item {
-                count += 1;
-            } else if item < right_item {
-                break;
-            }
-            right_index += 1;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.as_slice() {
+        // every column against every other
+        [flag] if flag == "--matrix" => print!("{}", or_exit(day_1a::report(&input), &input)),
+        // two columns, counted from 1
+        [a, b] => {
+            let columns = or_exit(day_1a::parse_columns(&input), &input);
+            let comparison = match (a.parse::<usize>(), b.parse::<usize>()) {
+                (Ok(a), Ok(b)) if a > 0 && b > 0 => day_1a::compare(&columns, a - 1, b - 1),
+                _ => None,
+            };
+            let Some(comparison) = comparison else {
+                eprintln!("error: the columns go from 1 to {}", columns.len());
+                std::process::exit(1);
+            };
+
+            println!("{}", comparison.distance);
+            println!("{}", comparison.similarity);
+        }
+        // print the total distance and the score
+        _ => {
+            println!("{}", or_exit(day_1a::part_1(&input), &input));
+            println!("{}", or_exit(day_1a::part_2(&input), &input));
         }
-        score += item * count;
     }
-
-    // print the score
-    println!("{}", score);
 }
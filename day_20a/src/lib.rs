@@ -0,0 +1,222 @@
+use common::ParseError;
+use grid::{Dir, Grid, Point};
+
+const MIN_SAVE: u64 = 100;
+
+#[inline]
+fn opb(s: Option<bool>) -> bool {
+    s.unwrap_or_default()
+}
+
+// every point within `range` steps of `point`, with how many steps away it is
+fn nearby_points(point: Point, range: isize) -> impl Iterator<Item = (Point, isize)> {
+    (-range..=range).flat_map(move |dx: isize| {
+        let dy_range = range - dx.abs();
+        (-dy_range..=dy_range).map(move |dy| {
+            (point + Point::new(dx, dy), dy.abs() + dx.abs())
+        })
+    })
+}
+
+#[derive(Debug)]
+struct Map {
+    map: Grid<Option<i64>>,
+    start: Point,
+    end: Point
+}
+
+impl Map {
+    pub fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+
+        let grid = Grid::parse(input, |p, c| match c {
+            '#' => Some(None),
+            '.' => Some(Some(0)),
+            'S' => {
+                start = Some(p);
+                Some(Some(0))
+            },
+            'E' => {
+                end = Some(p);
+                Some(Some(0))
+            },
+            _ => None,
+        }, "one of `#`, `.`, `S` or `E`")?;
+
+        let mut map = Self {
+            map: grid,
+            start: start.ok_or_else(|| ParseError::missing(input, "a start `S` in the map"))?,
+            end: end.ok_or_else(|| ParseError::missing(input, "an end `E` in the map"))?,
+        };
+
+        // walk the track back from the end, numbering every step
+        let mut cur_point = map.end;
+        let mut cur_dist = 0;
+        let mut prev_dir: Option<Dir> = None;
+        while cur_point != map.start {
+            let next_dir = Dir::ALL
+                .into_iter()
+                .filter(|&dir| Some(dir.opposite()) != prev_dir)
+                .find(|&dir| map.get_point(cur_point.step(dir)).is_some())
+                .ok_or_else(|| ParseError::missing(input, "a single track from `S` to `E`"))?;
+
+            prev_dir = Some(next_dir);
+            cur_point = cur_point.step(next_dir);
+
+            cur_dist += 1;
+            map.set_point(cur_point, Some(cur_dist));
+        }
+
+        Ok(map)
+    }
+
+    #[inline]
+    pub fn get_point(&self, p: Point) -> Option<i64> {
+        self.map.get(p).copied().flatten()
+    }
+
+    #[inline]
+    pub fn set_point(&mut self, p: Point, v: Option<i64>) {
+        self.map[p] = v;
+    }
+
+    #[allow(unused)]
+    pub fn display(&self) -> String {
+        self.map.render(|_, c| match c {
+            None => '#',
+            Some(x) => {
+                (x % 10).to_string().chars().nth(0).unwrap()
+            }
+        })
+    }
+
+    // cheats can last up to `cheat_len` picoseconds
+    pub fn find_saves(&self, min: u64, cheat_len: isize) -> u64 {
+        let mut count = 0;
+        let min = min as i64;
+
+        // i64 is needed because the next thing might be higher than current
+
+        for (point, item) in self.map.iter() {
+            if let Some(item) = item {
+                let item = *item;
+
+                for (p, dist) in nearby_points(point, cheat_len) {
+                    if opb(self.get_point(p).map(|a|item-dist as i64-a >= min)) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    Map::from_str(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    let map = Map::from_str(input)?;
+
+    Ok(map.find_saves(MIN_SAVE, 2))
+}
+
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    let map = Map::from_str(input)?;
+
+    Ok(map.find_saves(MIN_SAVE, 20))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use grid::{Grid, Point};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::Map::from_str(EXAMPLE).unwrap().find_saves(20, 2), 5);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::Map::from_str(EXAMPLE).unwrap().find_saves(50, 20), 285);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    // how far every track cell is from `from`, found by walking the track
+    fn distances(track: &Grid<bool>, from: Point) -> HashMap<Point, usize> {
+        let mut dist = HashMap::from([(from, 0)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(p) = queue.pop_front() {
+            for n in track.neighbours(p) {
+                if track[n] && !dist.contains_key(&n) {
+                    dist.insert(n, dist[&p] + 1);
+                    queue.push_back(n);
+                }
+            }
+        }
+        dist
+    }
+
+    // tries a cheat from every track cell to every other one in range
+    fn find_saves_naive(input: &str, min: usize, cheat_len: usize) -> u64 {
+        let mut start = Point::default();
+        let mut end = Point::default();
+        let track = Grid::parse(input, |p, c| {
+            match c {
+                'S' => start = p,
+                'E' => end = p,
+                _ => (),
+            }
+            Some(c != '#')
+        }, "").unwrap();
+
+        let from_start = distances(&track, start);
+        let from_end = distances(&track, end);
+        let normal = from_start[&end];
+
+        let mut count = 0;
+        for (&a, &to_a) in &from_start {
+            for (&b, &from_b) in &from_end {
+                let cheat = a.manhattan(b);
+                if cheat <= cheat_len && to_a + cheat + from_b + min <= normal {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    proptest! {
+        #[test]
+        fn saves_match_naive(
+            size in 5_usize..16,
+            seed: u64,
+            min in 1_u64..30,
+            cheat_len in 2_isize..8,
+        ) {
+            // the generator's tracks never run alongside themselves, like the puzzle's
+            let input = generators::generate(20, Some(size), seed).unwrap();
+            let map = super::Map::from_str(&input).unwrap();
+            prop_assert_eq!(map.find_saves(min, cheat_len), find_saves_naive(&input, min as usize, cheat_len as usize));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+
+//use rayon::prelude::*;
+use common::ParseError;
+use itertools::Itertools;
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    get_num_pad(input.trim_end()).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    complexity(input, 2)
+}
+
+pub fn part_2(input: &str) -> Result<u64, ParseError> {
+    complexity(input, 25)
+}
+
+// the sum of each code's complexity with `dir_pads` directional keypads between us and the door
+fn complexity(input: &str, dir_pads: usize) -> Result<u64, ParseError> {
+    let input = input.trim_end();
+
+    let mut memo = HashMap::new();
+
+    let num_pad = get_num_pad(input)?;
+
+    let move_lens = num_pad.into_iter().map(|num_pad| {
+        find_count(num_pad, dir_pads, &mut memo)
+    });
+
+    /*const FIRST_HALF: usize = DIR_PADS / 2;
+    const SECOND_HALF: usize = DIR_PADS - FIRST_HALF;
+
+    let move_lens = num_pad.into_iter().enumerate().map(|(i, pad)| {
+        println!("input {}", i);
+        let first = (0..FIRST_HALF).fold(pad, |pad, n| {
+            println!("dir pad {}", n+1);
+
+            moves_to_moves(pad, &mut memo)
+        });
+
+        next_pad(first, SECOND_HALF, &mut memo)
+    });
+    */
+
+    //let move_lens = move_lens.map(|m|m.len());
+
+    /*
+    let move_lens = (0..DIR_PADS).fold(num_pad, |moves,i| {
+        println!("dir pad {i}");
+        moves.into_iter().map(|m|moves_to_moves(m, &mut memo)).collect()
+    }).into_iter().map(|m|m.len());
+    */
+
+    input
+        .split('\n')
+        .zip(move_lens)
+        .map(|(line, len)| {
+            // the pad only has ascii keys, so this can't split a character
+            let num = common::parse::<u16>(input, &line[0..3], "a three digit code")? as u64;
+            Ok(num * len)
+        })
+        .sum()
+}
+
+fn find_count(pad: Vec<Move>, pads: usize, memo: &mut HashMap<(Move, Move, usize), u64>) -> u64 {
+    vec_to_rec(pad, pads+1, memo)
+}
+
+fn find_count_rec(start: Move, end: Move, remaining: usize, memo: &mut HashMap<(Move, Move, usize), u64>) -> u64 {
+    if let Some(o) = memo.get(&(start, end, remaining)) {
+        return *o;
+    }
+    
+    if remaining == 0 {
+        return 1;
+    }
+
+    let start_p = start.to_point();
+    let end_p = end.to_point();
+    let moves = gen_path_to(false, start_p, end_p);
+    let o = vec_to_rec(moves, remaining, memo);
+
+    memo.insert((start, end, remaining), o);
+
+    o
+}
+
+fn vec_to_rec(moves: Vec<Move>, remaining: usize, memo: &mut HashMap<(Move, Move, usize), u64>) -> u64 {
+    
+
+    std::iter::once(Move::Press).chain(moves).tuple_windows().map(|(start, end)| {
+        find_count_rec(start, end, remaining-1, memo)
+    }).sum()
+}
+
+/*
+fn split_into_two(mut p: Vec<Move>) -> (Vec<Move>, Vec<Move>) {
+    let mid = p.len() / 2;
+    let right = p.split_off(mid);
+    let left = p;
+    (left, right)
+}
+
+fn split_into_three(mut p: Vec<Move>) -> (Vec<Move>, Vec<Move>, Vec<Move>) {
+    let first = p.len() / 3;
+    let r = p.split_off(first);
+    let left = p;
+    let (mid, right) = split_into_two(r);
+    (left, mid, right)
+}
+*/
+
+/*
+fn next_pad(prev: Vec<Move>, remaining: usize, memo: &mut HashMap<(Point, Point), Vec<Move>>) -> usize {
+    //println!("{}, {}", remaining, prev.len());
+    if remaining == 0 {
+        return prev.len();
+    }
+    // split into 4
+    let (one, two, three) = split_into_three(prev);
+
+    let one = moves_to_moves(one, memo);
+    let two = moves_to_moves(two, memo);
+    let three = moves_to_moves(three, memo);
+
+    let out =
+        next_pad(one, remaining-1, memo) +
+        next_pad(two, remaining-1, memo) +
+        next_pad(three, remaining-1, memo);
+
+    out
+}
+*/
+
+fn get_num_pad(input: &str) -> Result<Vec<Vec<Move>>, ParseError> {
+    //let mut memo = HashMap::new();
+
+    input
+        .split('\n')
+        .map(|item| {
+            if item.len() < 4 {
+                return Err(ParseError::at(input, item, "a code of three digits and `A`"));
+            }
+
+            let keys = item
+                .char_indices()
+                .map(|(i, c)| {
+                    char_to_point(c)
+                        .ok_or_else(|| ParseError::at(input, &item[i..i + c.len_utf8()], "a digit or `A`"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // every code starts with the arm on `A`
+            let moves = 
+                vec![Point::from_tuple((2, 3))]
+                .into_iter()
+                .chain(keys)
+                .tuple_windows()
+                .map(|(start, end)| {
+                    gen_path_to(true, start, end)
+                })
+                .concat();
+
+            Ok(moves)
+        }).collect()
+}
+
+#[allow(unused)]
+fn moves_to_inner_moves(moves: Vec<Move>) -> Vec<Move> {
+    let mut pos = Point::from_tuple((2, 0));
+
+    let mut new_moves = vec![];
+
+    for m in moves {
+        match m {
+            Move::Press => {
+                new_moves.push(Move::from_point(pos))
+            },
+            Move::Up => pos.y -= 1,
+            Move::Down => pos.y += 1,
+            Move::Left => pos.x -= 1,
+            Move::Right => pos.x += 1,
+        }
+    }
+
+    new_moves
+}
+
+#[allow(unused)]
+fn move_text_to_moves(mov_text: &str) -> Vec<Move> {
+    mov_text.chars().map(|c| {
+        match c {
+            '<' => Move::Left,
+            '>' => Move::Right,
+            '^' => Move::Up,
+            'v' => Move::Down,
+            'A' => Move::Press,
+            _ => unreachable!()
+        }
+    }).collect()
+}
+
+/*
+fn moves_to_moves(moves: Vec<Move>) -> Vec<Move> {
+    vec![Point::from_tuple((2, 0))]
+        .into_iter()
+        .chain(
+            moves
+                .into_iter()
+                .map(|m| {
+                    m.to_point()
+                })
+        )
+        .tuple_windows()
+        .map(|(start, end)| {
+            gen_path_to(false, start, end)
+        })
+        .concat()
+}
+*/
+
+fn char_to_point(c: char) -> Option<Point> {
+    Some(Point::from_tuple(
+        match c {
+            '7' => (0, 0),
+            '8' => (1, 0),
+            '9' => (2, 0),
+            '4' => (0, 1),
+            '5' => (1, 1),
+            '6' => (2, 1),
+            '1' => (0, 2),
+            '2' => (1, 2),
+            '3' => (2, 2),
+            '0' => (1, 3),
+            'A' => (2, 3),
+            _ => return None,
+        }
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+    Press
+}
+
+impl Move {
+    pub fn to_point(self) -> Point {
+        Point::from_tuple(match self {
+            Move::Up => (1, 0),
+            Move::Press => (2, 0),
+            Move::Left => (0, 1),
+            Move::Down => (1, 1),
+            Move::Right => (2, 1),
+        })
+    }
+
+    pub fn from_point(p: Point) -> Self {
+        match (p.x, p.y) {
+            (1, 0) => Move::Up,
+            (2, 0) => Move::Press,
+            (0, 1) => Move::Left,
+            (1, 1) => Move::Down,
+            (2, 1) => Move::Right,
+            _ => unreachable!("{} {}", p.x, p.y)
+        }
+    }
+
+    #[allow(unused)]
+    pub fn display_vec(v: &Vec<Move>) -> String {
+        let mut out = String::with_capacity(v.len());
+        for m in v {
+            out.push(match m {
+                Move::Up => '^',
+                Move::Down => 'v',
+                Move::Left => '<',
+                Move::Right => '>',
+                Move::Press => 'A',
+            })
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+struct Point {
+    x: u8,
+    y: u8
+}
+
+impl Point {
+    pub fn from_tuple(p: (u8, u8)) -> Self {
+        Self {
+            x: p.0,
+            y: p.1
+        }
+    }
+}
+
+fn gen_path_to(first: bool, cur_pos: Point, goto: Point) -> Vec<Move> {    
+    let mut moves = Vec::new();
+
+    let forward = cur_pos.x < goto.x;
+    let (start, end) = if forward { (cur_pos.x, goto.x) } else { (goto.x, cur_pos.x) };
+    let mut hor_moves = (start..end).map(|_| {
+        if forward {
+            Move::Right
+        } else {
+            Move::Left
+        }
+    }).collect();
+    let downward = cur_pos.y < goto.y;
+    let (start, end) = if downward { (cur_pos.y, goto.y) } else { (goto.y, cur_pos.y) };
+    let mut vert_moves = (start..end).map(|_| {
+        if downward {
+            Move::Down
+        } else {
+            Move::Up
+        }
+    }).collect();
+
+    if first && cur_pos.y == 3 && goto.x == 0 {
+        moves.append(&mut vert_moves);
+        moves.append(&mut hor_moves);
+    } else if cur_pos.x == 0 && (!first || goto.y == 3) {
+        moves.append(&mut hor_moves);
+        moves.append(&mut vert_moves);
+    } else if !first && goto.x == 0 {
+        moves.append(&mut vert_moves);
+        moves.append(&mut hor_moves);
+    } else {
+        if forward {
+            moves.append(&mut vert_moves);
+            moves.append(&mut hor_moves);
+        } else {
+            moves.append(&mut hor_moves);
+            moves.append(&mut vert_moves);
+        }
+    }
+
+    moves.push(Move::Press);
+
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    use proptest::prelude::*;
+
+    use super::{char_to_point, Move, Point};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // the puzzle only gives an example answer for two directional keypads
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 126384);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    const MOVES: [Move; 5] = [Move::Up, Move::Down, Move::Left, Move::Right, Move::Press];
+
+    // where an arm ends up after moving, or `None` if it left the pad or went over the gap
+    fn move_arm(arm: Point, m: Move, numeric: bool) -> Option<Point> {
+        let (x, y) = match m {
+            Move::Up => (arm.x, arm.y.checked_sub(1)?),
+            Move::Down => (arm.x, arm.y + 1),
+            Move::Left => (arm.x.checked_sub(1)?, arm.y),
+            Move::Right => (arm.x + 1, arm.y),
+            Move::Press => unreachable!(),
+        };
+        let (height, gap) = if numeric { (4, (0, 3)) } else { (2, (0, 0)) };
+        (x < 3 && y < height && (x, y) != gap).then_some(Point::from_tuple((x, y)))
+    }
+
+    // the fewest presses we need for `code`, found by a breadth first search over where every
+    // arm is and how much of the code has been typed
+    fn presses_naive(code: &str, dir_pads: usize) -> u64 {
+        let keys = code.chars().map(|c| char_to_point(c).unwrap()).collect::<Vec<_>>();
+
+        // the directional arms, then the numeric arm, all starting on `A`
+        let mut arms = vec![Move::Press.to_point(); dir_pads];
+        arms.push(char_to_point('A').unwrap());
+        let start = (arms, 0);
+
+        let mut seen = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some(((arms, typed), presses)) = queue.pop_front() {
+            if typed == keys.len() {
+                return presses;
+            }
+
+            for m in MOVES {
+                let mut arms = arms.clone();
+                let mut typed = typed;
+                let mut m = m;
+                let mut valid = true;
+                // a press on one pad passes on whatever its arm is pointing at to the next one
+                for i in 0..arms.len() {
+                    let numeric = i == arms.len() - 1;
+                    if m != Move::Press {
+                        match move_arm(arms[i], m, numeric) {
+                            Some(arm) => arms[i] = arm,
+                            None => valid = false,
+                        }
+                        break;
+                    }
+                    if numeric {
+                        valid = arms[i] == keys[typed];
+                        typed += 1;
+                    } else {
+                        m = Move::from_point(arms[i]);
+                    }
+                }
+
+                let state = (arms, typed);
+                if valid && seen.insert(state.clone()) {
+                    queue.push_back((state, presses + 1));
+                }
+            }
+        }
+
+        unreachable!("every code can be typed")
+    }
+
+    proptest! {
+        #[test]
+        fn count_matches_naive(code in "[0-9]{3}A", dir_pads in 0_usize..4) {
+            let pad = super::get_num_pad(&code).unwrap().remove(0);
+            let count = super::find_count(pad, dir_pads, &mut HashMap::new());
+            prop_assert_eq!(count, presses_naive(&code, dir_pads));
+        }
+    }
+}
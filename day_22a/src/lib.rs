@@ -0,0 +1,140 @@
+//use cached::proc_macro::cached;
+
+use std::collections::HashMap;
+
+use common::ParseError;
+
+//#[cached]
+fn evolve_number(number: i64) -> i64 {
+    let step_1 = prune(mix(number, number*64));
+    let step_2 = prune(mix(step_1, step_1/32));
+    
+    prune(mix(step_2, step_2*2048))
+}
+
+//#[cached]
+fn mix(a: i64, b: i64) -> i64 {
+    // bitwise xor
+    a ^ b
+}
+
+//#[cached]
+fn prune(number: i64) -> i64 {
+    // mod 16777216
+    number % 16777216
+}
+
+fn price(number: i64) -> i64 {
+    number % 10
+}
+
+const ITERATIONS: usize = 2000;
+
+fn parse_numbers(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.lines().map(|line| common::parse(input, line, "a secret number")).collect()
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_numbers(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    Ok(parse_numbers(input)?
+        .into_iter()
+        .map(|number| (0..ITERATIONS).fold(number, |n, _| evolve_number(n)))
+        .sum())
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    let numbers = parse_numbers(input)?;
+
+    let mut sequences = Vec::new();
+
+    for (monkey, &number) in numbers.iter().enumerate() {
+        let mut final_number = number;
+        let mut prev_price = price(final_number);
+        let mut previous_changes = Vec::new();
+
+        for i in 0..ITERATIONS {
+            final_number = evolve_number(final_number);
+            let price = price(final_number);
+            //println!("{}: {} ({})", final_number, price, price-prev_price);
+            previous_changes.push(price-prev_price);
+            if previous_changes.len() > 4 {
+                previous_changes.remove(0);
+            }
+
+            if i > 3 {
+                sequences.push((monkey, price, previous_changes.clone()));
+            }
+
+            prev_price = price;
+        }
+    }
+
+    common::diag!(2, "Sequence finding complete");
+
+    let mut sequences_total: HashMap<Vec<i64>, HashMap<usize, i64>> = HashMap::new();
+
+    for (monkey, price, sequence) in sequences {
+        // we can only take one sequence per monkey
+        // we need to find the sequence that gives the most total (get the sequence from all monkeys and sum the price)
+        match sequences_total.get_mut(&sequence) {
+            Some(monkeys) => {
+                // monkeys is a hashmap
+                monkeys.entry(monkey).or_insert(price);
+            }
+            None => {
+                let mut monkeys = HashMap::new();
+                monkeys.insert(monkey, price);
+                sequences_total.insert(sequence, monkeys);
+            }
+        }
+    }
+
+    let max_sequence = sequences_total.iter().max_by_key(|(_, monkeys)| {
+        monkeys.values().sum::<i64>()
+    });
+
+    // every monkey has a sequence, so there's only none when there are no monkeys
+    let max_sequence = match max_sequence {
+        Some(max_sequence) => {
+            max_sequence
+        }
+        None => {
+            return Err(ParseError::missing(input, "a secret number"));
+        }
+    };
+
+    common::diag!(1, "{:?}", max_sequence.0);
+
+    Ok(max_sequence.1.values().sum::<i64>())
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 37327623);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE_2).unwrap(), 23);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+
+use common::ParseError;
+
+#[derive(Debug, Clone)]
+pub struct Node<'a> {
+    pub name: &'a str,
+    pub connections: HashSet<&'a str>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            connections: HashSet::new()
+        }
+    }
+
+    pub fn add_connection(&mut self, other: &'a str) {
+        self.connections.insert(other);
+    }
+
+    pub fn connect(&mut self, other: &mut Self) {
+        self.add_connection(other.name);
+        other.add_connection(self.name);
+    }
+}
+
+type Graph<'a> = HashMap<&'a str, Node<'a>>;
+
+fn new_graph<'a>(input: &'a str) -> Result<Graph<'a>, ParseError> {
+    let mut graph = Graph::new();
+
+    for line in input.split('\n') {
+        let (left, right) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input, line, "two computers joined by `-`"))?;
+
+        let has_left = graph.contains_key(left);
+        let has_right = graph.contains_key(right);
+
+        if has_left && has_right {
+            graph.get_mut(left).unwrap().add_connection(right);
+            graph.get_mut(right).unwrap().add_connection(left);
+        } else if has_left {
+            let mut right_n = Node::new(right);
+
+            graph.get_mut(left).unwrap().connect(&mut right_n);
+
+            graph.insert(right, right_n);
+        } else if has_right {
+            let mut left_n = Node::new(left);
+
+            graph.get_mut(right).unwrap().connect(&mut left_n);
+
+            graph.insert(left, left_n);
+        } else {
+            let mut left_n = Node::new(left);
+            let mut right_n = Node::new(right);
+
+            left_n.connect(&mut right_n);
+
+            graph.insert(left, left_n);
+            graph.insert(right, right_n);
+        }
+    }
+
+    Ok(graph)
+}
+
+#[derive(Debug, Clone)]
+pub struct Threes<'a> {
+    set: HashSet<(&'a str, &'a str, &'a str)>
+}
+
+impl Default for Threes<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Threes<'a> {
+    pub fn new() -> Self {
+        Self {
+            set: HashSet::new()
+        }
+    }
+
+    pub fn insert(&mut self, item: (&'a str, &'a str, &'a str)) -> bool {
+        let mut vec_form = [item.0, item.1, item.2];
+        vec_form.sort();
+        let new_item = (vec_form[0], vec_form[1], vec_form[2]);
+
+        self.set.insert(new_item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+}
+
+fn find_t_threes<'a>(graph: Graph<'a>) -> Threes<'a> {
+    let mut ts = Vec::new();
+
+    for &node_name in graph.keys() {
+        if node_name.starts_with('t') {
+            ts.push(node_name);
+        }
+    }
+
+    let mut threes = Threes::new();
+
+    for t in ts {
+        let node = graph.get(t).unwrap();
+
+        for &c in &node.connections {
+            let node = graph.get(c).unwrap();
+            for &i in &node.connections {
+                let node = graph.get(i).unwrap();
+                if node.connections.iter().find(|&&s|s==t).is_some() {
+                    threes.insert((t, c, i));
+                }
+            }
+        }
+    }
+
+    threes
+}
+
+fn find_group(graph: &Graph, id: &str, current_members: &mut Vec<String>) {
+    let node = graph.get(id).unwrap();
+
+    let mut new_members = vec![];
+
+    for con in &node.connections {
+        let node = graph.get(con).unwrap();
+
+        if current_members.iter().filter(|&m| {
+            !node.connections.contains(m.as_str())
+        }).collect::<Vec<_>>().is_empty() {
+            current_members.push(con.to_string());
+            new_members.push(con);
+        }
+    }
+
+    for &con in new_members {
+        find_group(graph, con, current_members);
+    }
+}
+
+fn find_max_group(graph: Graph) -> Vec<String> {
+    let mut max_group = vec![];
+
+    let len = graph.len();
+
+    let mut checked = 0;
+    for n in graph.keys() {
+        let mut group = Vec::new();
+        find_group(&graph, n, &mut group);
+        if group.len() > max_group.len() {
+            max_group = group;
+        }
+        checked += 1;
+        common::diag!(2, "{}/{}", checked, len);
+    }
+
+    max_group
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    new_graph(input.trim_end()).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let graph = new_graph(input.trim_end())?;
+
+    Ok(find_t_threes(graph).len())
+}
+
+pub fn part_2(input: &str) -> Result<String, ParseError> {
+    let graph = new_graph(input.trim_end())?;
+
+    let mut max_g = find_max_group(graph);
+    max_g.sort();
+
+    Ok(max_g.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 7);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,443 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use common::{next_field, ParseError};
+use hashbrown::HashMap;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct StrGate {
+    pub gate: GateType,
+    pub left: String,
+    pub right: String,
+    pub output: String,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Gate {
+    pub gate: GateType,
+    pub left: usize,
+    pub right: usize,
+    pub output: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateType {
+    And,
+    Xor,
+    Or,
+}
+
+impl GateType {
+    pub fn parse(gate: &str) -> Option<Self> {
+        match gate {
+            "AND" => Some(Self::And),
+            "XOR" => Some(Self::Xor),
+            "OR" => Some(Self::Or),
+            _ => None,
+        }
+    }
+}
+
+type WireConversions = BTreeMap<usize, String>;
+type WCBack = BTreeMap<String, usize>;
+type Gates = Vec<Option<Gate>>;
+type Swap = (usize, usize);
+type Swaps = [Swap; 4];
+type Vals = Vec<Option<bool>>;
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    get_vals_and_gates(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u64, ParseError> {
+    let (gates, vals, _, _, wc_front, _) = get_vals_and_gates(input)?;
+
+    Ok(run_with_swaps(&gates, &vals, &blank_swaps(), &wc_front))
+}
+
+type Parsed = (Gates, Vals, u64, u64, WireConversions, WCBack);
+
+fn get_vals_and_gates(input: &str) -> Result<Parsed, ParseError> {
+    let mut vals = HashMap::with_capacity(350);
+
+    let (starting_vals, gate_lines) = input
+        .trim_end()
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::missing(input, "an empty line between the wires and the gates"))?;
+
+    let mut x_inputs = vec![];
+    let mut y_inputs = vec![];
+
+    for starting_val in starting_vals.split('\n') {
+        let (name, val) = starting_val
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(input, starting_val, "a wire and its value separated by `: `"))?;
+
+        let name = name.to_string();
+        let val = match val {
+            "0" => false,
+            "1" => true,
+            _ => return Err(ParseError::at(input, val, "`0` or `1`")),
+        };
+
+        vals.insert(name.clone(), val);
+
+        if name.starts_with("x") {
+            x_inputs.push((name, val));
+        } else if name.starts_with("y") {
+            y_inputs.push((name, val));
+        }
+
+        if x_inputs.len() > 64 || y_inputs.len() > 64 {
+            return Err(ParseError::at(input, starting_val, "at most 64 bits in each number"));
+        }
+    }
+
+    // the first wire is the lowest bit
+    let number = |inputs: &[(String, bool)]| inputs.iter().rev().fold(0, |n, &(_, bit)| n << 1 | bit as u64);
+    let x_num = number(&x_inputs);
+    let y_num = number(&y_inputs);
+
+    let gate_lines = gate_lines.split('\n');
+
+    let lines_len = match gate_lines.try_len() {
+        Ok(l) => l,
+        Err(e) => {
+            if let Some(l) = e.1 { l } else { e.0 }
+        }
+    };
+
+    let mut gates = HashMap::with_capacity(lines_len);
+
+    let (mut wc_front, mut wc_back) = (WireConversions::new(), WCBack::new());
+    let mut n = 0;
+
+    for line in gate_lines {
+        // lines look like `x00 AND y00 -> z00`
+        let mut parts = line.split(' ');
+
+        let left = next_field(input, line, &mut parts, "a wire")?.to_string();
+        let gate = next_field(input, line, &mut parts, "a gate")?;
+        let gate_type = GateType::parse(gate).ok_or_else(|| ParseError::at(input, gate, "`AND`, `XOR` or `OR`"))?;
+        let right = next_field(input, line, &mut parts, "a wire")?.to_string();
+        let arrow = next_field(input, line, &mut parts, "`->`")?;
+        if arrow != "->" {
+            return Err(ParseError::at(input, arrow, "`->`"));
+        }
+        let output = next_field(input, line, &mut parts, "an output wire")?.to_string();
+
+        #[inline]
+        fn insert_wire(w: String, wc_front: &mut WireConversions, wc_back: &mut WCBack, n: &mut usize) {
+            wc_back.entry(w.clone()).or_insert_with(|| {
+                *n += 1;
+                wc_front.insert(*n, w);
+                *n
+            });
+        }
+
+        insert_wire(left.clone(), &mut wc_front, &mut wc_back, &mut n);
+        insert_wire(right.clone(), &mut wc_front, &mut wc_back, &mut n);
+        insert_wire(output.clone(), &mut wc_front, &mut wc_back, &mut n);
+
+        gates.insert(output.clone(), StrGate {
+            gate: gate_type,
+            left,
+            right,
+            output,
+        });
+    }
+
+    let mut max_out = 0;
+    let gates_iter = gates.into_iter().map(|(i, gate)| {
+        let idx = *wc_back.get(&i).unwrap();
+
+        if idx > max_out {
+            max_out = idx;
+        }
+        
+        (idx, Gate {
+            gate: gate.gate,
+            left: *wc_back.get(&gate.left).unwrap(),
+            right: *wc_back.get(&gate.right).unwrap(),
+            output: *wc_back.get(&gate.output).unwrap()
+        })
+    }).collect::<Vec<_>>();
+
+    let gates_len = max_out+1;
+    let mut gates = Vec::with_capacity(gates_len);
+    gates.resize(gates_len, None);
+    gates_iter.into_iter().for_each(|g|gates[g.0] = Some(g.1));
+
+    // a starting wire no gate reads can't change anything
+    let vals_iter = vals.into_iter().filter_map(|(i, val)| {
+        let idx = *wc_back.get(&i)?;
+        Some((idx, val))
+    }).collect::<Vec<_>>();
+
+    let vals_len = n + 1;
+    let mut vals = Vec::with_capacity(vals_len);
+    vals.resize(vals_len, None);
+    vals_iter.into_iter().for_each(|v|vals[v.0] = Some(v.1));
+
+    Ok((gates, vals, x_num, y_num, wc_front, wc_back))
+}
+
+// the circuit is meant to be a ripple carry adder, where bit n of the sum is
+//   z = (x ^ y) ^ carry_in,  carry_out = (x & y) | ((x ^ y) & carry_in)
+// with bit 0 a half adder and the last carry the highest z. a gate whose output is wired up in
+// a way no gate of that kind is in an adder has had its output swapped, so this only has to look
+// at how each wire is used, and works for any number of bits
+pub fn part_2(input: &str) -> Result<String, ParseError> {
+    let (gates, _, _, _, wc_front, _) = get_vals_and_gates(input)?;
+    let gates: Vec<&Gate> = gates.iter().flatten().collect();
+
+    let name = |wire: usize| wc_front[&wire].as_str();
+    let is_input = |wire: usize| name(wire).starts_with(['x', 'y']);
+    let feeds = |wire: usize, gate: GateType| gates.iter().any(|g| g.gate == gate && (g.left == wire || g.right == wire));
+    let last_z = gates
+        .iter()
+        .map(|g| name(g.output))
+        .filter(|output| output.starts_with('z'))
+        .max()
+        .ok_or_else(|| ParseError::missing(input, "a gate with a `z` output"))?;
+
+    let wrong = gates
+        .iter()
+        .filter(|g| {
+            let output = name(g.output);
+            let from_inputs = is_input(g.left) && is_input(g.right);
+            let first_bit = from_inputs && name(g.left).ends_with("00");
+
+            if output == last_z {
+                return g.gate != GateType::Or;
+            }
+            if output.starts_with('z') {
+                return g.gate != GateType::Xor || (from_inputs && !first_bit);
+            }
+
+            match g.gate {
+                // adding in the carry makes a z, and x ^ y goes on to be added to the carry
+                GateType::Xor => !from_inputs || !feeds(g.output, GateType::Xor),
+                // both halves of the carry go into an OR, apart from bit 0's, which is the carry
+                GateType::And => !first_bit && !feeds(g.output, GateType::Or),
+                GateType::Or => !feeds(g.output, GateType::Xor),
+            }
+        })
+        .map(|g| name(g.output))
+        .sorted()
+        .collect::<Vec<_>>();
+
+    if wrong.len() != 8 {
+        return Err(ParseError::missing(input, "gates that add up once four pairs of outputs are swapped"));
+    }
+
+    Ok(wrong.join(","))
+}
+
+#[inline]
+fn blank_swaps() -> Swaps {
+    let m = usize::MAX;
+    [
+        (m, m),
+        (m, m),
+        (m, m),
+        (m, m)
+    ]
+}
+
+//#[inline]
+fn check_swap(swap: &Swap, a: usize) -> Option<usize> {
+    Some(if swap.0 == a { swap.1 }
+    else if swap.1 == a { swap.0 }
+    else { return None })
+}
+
+//#[inline]
+fn get_actual_from_swaps(swaps: &Swaps, a: usize) -> usize {
+    if let Some(s) = check_swap(&swaps[0], a) { return s }
+    if let Some(s) = check_swap(&swaps[1], a) { return s }
+    if let Some(s) = check_swap(&swaps[2], a) { return s }
+    if let Some(s) = check_swap(&swaps[3], a) { return s }
+
+    a
+}
+
+#[cfg_attr(not(debug_assertions), inline)]
+fn swap_vals_contains(vals: &Vals, vals2: &Vals, v: usize, swaps: &Swaps) -> bool {
+    let v = get_actual_from_swaps(swaps, v);
+    
+    vals_contains(vals, vals2, v)
+}
+
+#[cfg_attr(not(debug_assertions), inline)]
+fn vals_contains(starting_vals: &Vals, our_vals: &Vals, s: usize) -> bool {
+    iv_contains(starting_vals, s) || iv_contains(our_vals, s)
+}
+
+#[inline]
+fn iv_contains(v: &Vals, s: usize) -> bool {
+    v[s].is_some()
+}
+
+#[cfg_attr(not(debug_assertions), inline)]
+fn vals_get(starting_vals: &Vals, our_vals: &Vals, s: usize) -> Option<bool> {
+    Some(if let Some(Some(b)) = our_vals.get(s) {
+        *b
+    } else if let Some(Some(b)) = starting_vals.get(s) {
+        *b
+    } else {
+        return None;
+    })
+}
+
+fn run_with_swaps(gates: &Gates, starting_vals: &Vals, swaps: &Swaps, wc: &WireConversions) -> u64 {
+    let mut since_last_complete = 0;
+    let len = gates.len();
+
+    let sv = starting_vals;
+    let ov_len = gates.len();
+    let mut ov = Vec::with_capacity(ov_len);
+    ov.resize(ov_len, None);
+    let mut completed = 0;
+    let mut current_gate = 0;
+
+    let actual_len = gates.iter().fold(0, |a,g|{
+        if g.is_some() { a + 1 } else { a }
+    });
+
+    loop {
+        if since_last_complete > actual_len {
+            return 0;
+        }
+
+        if current_gate >= len {
+            current_gate = 0;
+        }
+        let gate = gates.get(current_gate).unwrap();
+
+        let gate = 
+        if let Some(gate) = gate { gate }
+        else {
+            current_gate += 1;
+            continue;
+        };
+        since_last_complete += 1;
+
+        let has_left = vals_contains(sv, &ov, gate.left);
+        let has_right = vals_contains(sv, &ov, gate.right);
+        let has_out = swap_vals_contains(sv, &ov, gate.output, swaps);
+
+        if has_left && has_right && !has_out {
+            since_last_complete = 0;
+            let left = vals_get(sv, &ov, gate.left).unwrap();
+            let right = vals_get(sv, &ov, gate.right).unwrap();
+
+            let val = match gate.gate {
+                GateType::And => left && right,
+                GateType::Or => left || right,
+                GateType::Xor => left ^ right,
+            };
+
+            let out = get_actual_from_swaps(swaps, gate.output);
+
+            ov[out] = Some(val);
+            completed += 1;
+            if completed >= actual_len {
+                break;
+            }
+        }
+
+        current_gate += 1;
+    }
+
+    let mut zs = Vec::new();
+    for v in ov.iter().enumerate() {
+        let s = wc.get(&v.0);
+        if let (Some(s), Some(v)) = (s, v.1) {
+            if s.starts_with('z') {
+                zs.push((s, *v));
+            }
+        }
+    }
+
+    zs.sort_by(|a,b| {
+        a.0.cmp(b.0)
+    });
+
+    zs.reverse();
+
+    let mut z_val = 0;
+    for z in zs {
+        z_val *= 2;
+        z_val += if z.1 { 1 } else { 0 };
+    }
+
+    z_val
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // part 2 looks for swapped wires in an adder, and the examples aren't adders
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 4);
+    }
+
+    #[test]
+    fn not_an_adder_or_too_many_bits() {
+        let error = super::part_2(EXAMPLE).unwrap_err();
+        assert!(error.expected.contains("four pairs of outputs"), "{}", error);
+
+        let wires: String = (0..65).map(|bit| format!("x{:02}: 1\n", bit)).collect();
+        assert!(super::parse(&format!("{}\nx00 AND x01 -> z00\n", wires)).is_err());
+    }
+
+    // every way of putting the wires into pairs
+    fn pairings(wires: &[usize]) -> Vec<Vec<super::Swap>> {
+        let Some((&first, rest)) = wires.split_first() else {
+            return vec![Vec::new()];
+        };
+        let mut all = Vec::new();
+        for (i, &other) in rest.iter().enumerate() {
+            let left: Vec<usize> = rest.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, &w)| w).collect();
+            for mut pairs in pairings(&left) {
+                pairs.push((first, other));
+                all.push(pairs);
+            }
+        }
+        all
+    }
+
+    #[test]
+    fn generated_swaps_make_an_adder() {
+        for size in [8, 45, 63] {
+            for seed in 0..4 {
+                let input = generators::generate(24, Some(size), seed).unwrap();
+                let answer = super::part_2(&input).unwrap();
+                let (gates, vals, x, y, wc_front, wc_back) = super::get_vals_and_gates(&input).unwrap();
+                let wires: Vec<usize> = answer.split(',').map(|name| wc_back[name]).collect();
+
+                // some way of swapping the outputs back has to add the inputs up
+                let adds = pairings(&wires).into_iter().any(|pairs| {
+                    let swaps: super::Swaps = pairs.try_into().unwrap();
+                    super::run_with_swaps(&gates, &vals, &swaps, &wc_front) == x + y
+                });
+                assert!(adds, "size {} seed {}: {}", size, seed, answer);
+            }
+        }
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,124 @@
+use common::ParseError;
+
+#[derive(Debug, Clone)]
+struct Schematic {
+    pub heights: [u8; 5],
+    pub is_lock: bool,
+}
+
+impl Schematic {
+    pub fn new(heights: [u8; 5], is_lock: bool) -> Self {
+        Schematic { heights, is_lock }
+    }
+
+    pub fn from_str(input: &str, s: &str) -> Result<Self, ParseError> {
+        let mut lines = s.lines();
+
+        let is_lock = lines
+            .next()
+            .ok_or_else(|| ParseError::at(input, s, "a lock or a key"))?
+            .starts_with('#');
+
+        /*
+        ####.
+        ###..
+        ##...
+        #....
+        heights:
+        43210
+        
+         */
+
+        let mut heights = [0; 5];
+        
+        for line in lines.take(5) {
+            for (i, (j, c)) in line.char_indices().enumerate() {
+                let cell = &line[j..j + c.len_utf8()];
+                match c {
+                    '#' if i < heights.len() => heights[i] += 1,
+                    '.' if i < heights.len() => (),
+                    '#' | '.' => return Err(ParseError::at(input, cell, "a schematic 5 columns wide")),
+                    _ => return Err(ParseError::at(input, cell, "`#` or `.`")),
+                }
+            }
+        }
+
+        Ok(Schematic::new(heights, is_lock))
+    }
+}
+
+struct Schematics {
+    pub locks: Vec<Schematic>,
+    pub keys: Vec<Schematic>,
+}
+
+fn parse_schematics(input: &str) -> Result<Schematics, ParseError> {
+    let mut schematics = Schematics {
+        locks: Vec::new(),
+        keys: Vec::new(),
+    };
+
+    for s in input.split("\n\n") {
+        let schematic = Schematic::from_str(input, s)?;
+
+        if schematic.is_lock {
+            schematics.locks.push(schematic);
+        } else {
+            schematics.keys.push(schematic);
+        }
+    }
+
+    Ok(schematics)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_schematics(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<u32, ParseError> {
+    let schematics = parse_schematics(input)?;
+
+    let mut successes = 0;
+    for lock in &schematics.locks {
+        for key in &schematics.keys {
+            let mut success = true;
+            for (height_a, height_b) in lock.heights.iter().zip(key.heights.iter()) {
+                if *height_a + *height_b > 5 {
+                    success = false;
+                    break;
+                }
+            }
+            successes += success as u32;
+        }
+    }
+
+    Ok(successes)
+}
+
+// day 25 only has one puzzle, the second star is for finishing every other day
+pub fn part_2(_input: &str) -> Result<&'static str, ParseError> {
+    Ok("Merry Christmas!")
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // there is no part 2 puzzle, so no part 2 example
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 3);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
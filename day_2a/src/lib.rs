@@ -0,0 +1,383 @@
+use std::fmt;
+
+use common::ParseError;
+
+fn parse_report(input: &str, report: &str) -> Result<Vec<i32>, ParseError> {
+    // split by space
+    let levels: Vec<i32> = report.split_whitespace()
+        // parse each element to i32
+        .map(|x| common::parse(input, x, "a level"))
+        // collect to Vec<i32>
+        .collect::<Result<_, _>>()?;
+
+    if levels.is_empty() {
+        return Err(ParseError::at(input, report, "at least one level"));
+    }
+
+    Ok(levels)
+}
+
+/// Whether the levels go up (`True`), down (`False`), or haven't moved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bool3 {
+    True,
+    False,
+    Unknown,
+}
+
+/// Why a report is unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// two neighbouring levels are further apart than the tolerance allows
+    StepTooLarge,
+    /// two neighbouring levels are closer than the tolerance allows, but not equal
+    StepTooSmall,
+    ZeroStep,
+    DirectionChange,
+}
+
+impl Reason {
+    pub const ALL: [Self; 4] = [Self::StepTooLarge, Self::StepTooSmall, Self::ZeroStep, Self::DirectionChange];
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::StepTooLarge => "step too large",
+            Self::StepTooSmall => "step too small",
+            Self::ZeroStep => "zero step",
+            Self::DirectionChange => "direction change",
+        })
+    }
+}
+
+/// What `verdict` makes of a report, along with the direction it worked out from the first step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe { increasing: Bool3 },
+    /// `index` is the first level that doesn't follow on from the one before it
+    Unsafe { index: usize, reason: Reason, increasing: Bool3 },
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        matches!(self, Self::Safe { .. })
+    }
+}
+
+/// How far apart neighbouring levels may be, and how many levels the Problem Dampener may take
+/// out of a report to make it safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub removals: usize,
+}
+
+impl Tolerance {
+    pub const PART_1: Self = Self { min_step: 1, max_step: 3, removals: 0 };
+    pub const PART_2: Self = Self { min_step: 1, max_step: 3, removals: 1 };
+}
+
+/// Checks a report with nothing removed, stopping at the first level that breaks the rules. The
+/// first step that isn't zero decides the direction, and a zero step (when `min_step` allows one)
+/// fits either. A report with no levels is safe.
+pub fn verdict(report: &[i32], tolerance: &Tolerance) -> Verdict {
+    let Some(&first) = report.first() else {
+        return Verdict::Safe { increasing: Bool3::Unknown };
+    };
+    let mut prev = first;
+    let mut increasing = Bool3::Unknown;
+
+    for (index, &current) in report.iter().enumerate().skip(1) {
+        let step = (current - prev).abs();
+        let reason = if step == 0 && tolerance.min_step > 0 {
+            Some(Reason::ZeroStep)
+        } else if step > tolerance.max_step {
+            Some(Reason::StepTooLarge)
+        } else if step < tolerance.min_step {
+            Some(Reason::StepTooSmall)
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            return Verdict::Unsafe { index, reason, increasing };
+        }
+
+        match increasing {
+            Bool3::Unknown => {
+                if current > prev {
+                    increasing = Bool3::True;
+                } else if current < prev {
+                    increasing = Bool3::False;
+                }
+            },
+            Bool3::True => {
+                if current < prev {
+                    return Verdict::Unsafe { index, reason: Reason::DirectionChange, increasing };
+                }
+            },
+            Bool3::False => {
+                if current > prev {
+                    return Verdict::Unsafe { index, reason: Reason::DirectionChange, increasing };
+                }
+            },
+        }
+
+        prev = current;
+    }
+
+    Verdict::Safe { increasing }
+}
+
+/// The fewest levels to remove from `report` to make it safe, in order, or `None` if that takes
+/// more than `tolerance.removals`.
+pub fn dampen(report: &[i32], tolerance: &Tolerance) -> Option<Vec<usize>> {
+    let increasing = dampen_towards(report, tolerance, 1);
+    let decreasing = dampen_towards(report, tolerance, -1);
+
+    match (increasing, decreasing) {
+        (Some(a), Some(b)) => Some(if b.len() < a.len() { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+// `sign` is 1 for a report that goes up and -1 for one that goes down. Only the last k + 1 levels
+// can come straight before any kept level (anything further back means removing too many), so
+// this is O(n * k)
+fn dampen_towards(report: &[i32], tolerance: &Tolerance, sign: i32) -> Option<Vec<usize>> {
+    let len = report.len();
+    let k = tolerance.removals;
+    if len == 0 {
+        return Some(Vec::new());
+    }
+    let fits = |from: usize, to: usize| {
+        let step = (report[to] - report[from]) * sign;
+        tolerance.min_step <= step && step <= tolerance.max_step
+    };
+
+    // for each level, the fewest removals that leave a safe report ending on it, and the level
+    // kept before it
+    let mut best: Vec<Option<(usize, Option<usize>)>> = vec![None; len];
+    for i in 0..len {
+        // everything before it removed
+        if i <= k {
+            best[i] = Some((i, None));
+        }
+
+        for prev in i.saturating_sub(k + 1)..i {
+            let Some((removed, _)) = best[prev] else { continue };
+            let removed = removed + (i - prev - 1);
+
+            if removed <= k && fits(prev, i) && best[i].is_none_or(|(r, _)| removed < r) {
+                best[i] = Some((removed, Some(prev)));
+            }
+        }
+    }
+
+    // and everything after the last kept level removed
+    let (last, _) = (len.saturating_sub(k + 1)..len)
+        .filter_map(|i| best[i].map(|(removed, _)| (i, removed + (len - 1 - i))))
+        .filter(|&(_, removed)| removed <= k)
+        .min_by_key(|&(_, removed)| removed)?;
+
+    let mut kept = vec![false; len];
+    let mut at = Some(last);
+    while let Some(i) = at {
+        kept[i] = true;
+        at = best[i].and_then(|(_, prev)| prev);
+    }
+
+    Some((0..len).filter(|&i| !kept[i]).collect())
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    // split by new line
+    let lines = input.lines();
+
+    // collect reports
+    lines.map(|line| parse_report(input, line)).collect()
+}
+
+fn count_safe(input: &str, is_safe: impl Fn(&[i32]) -> bool) -> Result<usize, ParseError> {
+    let reports = parse_reports(input)?;
+
+    let mut safe_reports = 0;
+    // iterate over reports
+    for report in reports {
+        if is_safe(&report) {
+            safe_reports += 1;
+        }
+    }
+
+    Ok(safe_reports)
+}
+
+/// The number of reports that are safe under `tolerance`.
+pub fn count_dampened(input: &str, tolerance: &Tolerance) -> Result<usize, ParseError> {
+    count_safe(input, |report| dampen(report, tolerance).is_some())
+}
+
+/// One line per report with its verdict under `tolerance` (and what the dampener would remove,
+/// if it can make the report safe), then how many reports failed for each reason.
+pub fn report(input: &str, tolerance: &Tolerance) -> Result<String, ParseError> {
+    let reports = parse_reports(input)?;
+
+    let direction = |increasing| match increasing {
+        Bool3::True => "increasing",
+        Bool3::False => "decreasing",
+        Bool3::Unknown => "no direction",
+    };
+
+    let mut out = String::new();
+    let mut safe = 0;
+    let mut dampened = 0;
+    let mut failures = [0; Reason::ALL.len()];
+
+    for (number, report) in reports.iter().enumerate() {
+        out += &format!("{}: ", number + 1);
+
+        match verdict(report, tolerance) {
+            Verdict::Safe { increasing } => {
+                safe += 1;
+                out += &format!("safe, {}", direction(increasing));
+            }
+            Verdict::Unsafe { index, reason, increasing } => {
+                failures[Reason::ALL.iter().position(|&r| r == reason).unwrap()] += 1;
+                out += &format!("unsafe at level {} ({}), {}", index + 1, reason, direction(increasing));
+
+                if let Some(removed) = dampen(report, tolerance).filter(|removed| !removed.is_empty()) {
+                    dampened += 1;
+                    let levels: Vec<String> = removed.iter().map(|i| (i + 1).to_string()).collect();
+                    out += &format!(", safe without level {}", levels.join(", "));
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    out += &format!("\nsafe: {}\n", safe);
+    if tolerance.removals > 0 {
+        out += &format!("safe once dampened: {}\n", dampened);
+    }
+    for (reason, count) in Reason::ALL.iter().zip(failures) {
+        out += &format!("{}: {}\n", reason, count);
+    }
+
+    Ok(out)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_reports(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    count_safe(input, |report| verdict(report, &Tolerance::PART_1).is_safe())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    count_dampened(input, &Tolerance::PART_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bool3, Reason, Tolerance, Verdict};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 2);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 4);
+    }
+
+    #[test]
+    fn verdicts_of_the_example() {
+        let reports = super::parse_reports(EXAMPLE).unwrap();
+        let verdicts: Vec<Verdict> = reports.iter().map(|r| super::verdict(r, &Tolerance::PART_1)).collect();
+
+        assert_eq!(verdicts, [
+            Verdict::Safe { increasing: Bool3::False },
+            Verdict::Unsafe { index: 2, reason: Reason::StepTooLarge, increasing: Bool3::True },
+            Verdict::Unsafe { index: 3, reason: Reason::StepTooLarge, increasing: Bool3::False },
+            Verdict::Unsafe { index: 2, reason: Reason::DirectionChange, increasing: Bool3::True },
+            Verdict::Unsafe { index: 3, reason: Reason::ZeroStep, increasing: Bool3::False },
+            Verdict::Safe { increasing: Bool3::True },
+        ]);
+    }
+
+    #[test]
+    fn verdicts_allowing_zero_steps() {
+        let tolerance = Tolerance { min_step: 0, max_step: 3, removals: 1 };
+
+        assert_eq!(super::verdict(&[1, 1, 2], &tolerance), Verdict::Safe { increasing: Bool3::True });
+        assert_eq!(super::verdict(&[4, 4, 4], &tolerance), Verdict::Safe { increasing: Bool3::Unknown });
+        assert_eq!(
+            super::verdict(&[3, 3, 2, 2, 4], &tolerance),
+            Verdict::Unsafe { index: 4, reason: Reason::DirectionChange, increasing: Bool3::False }
+        );
+        assert_eq!(super::dampen(&[1, 1, 2], &tolerance), Some(vec![]));
+
+        assert_eq!(super::verdict(&[], &Tolerance::PART_1), Verdict::Safe { increasing: Bool3::Unknown });
+        assert_eq!(super::dampen(&[], &Tolerance::PART_1), Some(vec![]));
+
+        let report = super::report("1 1 2\n", &tolerance).unwrap();
+        assert!(report.starts_with("1: safe"));
+        assert!(report.contains("safe: 1\n"));
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+
+    // tries every way of removing up to k levels
+    fn fewest_removals(report: &[i32], tolerance: &Tolerance) -> Option<usize> {
+        (0..1usize << report.len())
+            .filter(|mask| mask.count_ones() as usize <= tolerance.removals)
+            .filter(|mask| {
+                let kept: Vec<i32> = (0..report.len()).filter(|i| mask & (1 << i) == 0).map(|i| report[i]).collect();
+                [1, -1].iter().any(|sign| {
+                    kept.windows(2).all(|w| (tolerance.min_step..=tolerance.max_step).contains(&((w[1] - w[0]) * sign)))
+                })
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn dampen_matches_trying_every_removal() {
+        let reports = super::parse_reports(INPUT).unwrap();
+        let tolerances = [
+            Tolerance::PART_1,
+            Tolerance::PART_2,
+            Tolerance { min_step: 1, max_step: 3, removals: 3 },
+            Tolerance { min_step: 0, max_step: 5, removals: 2 },
+        ];
+
+        for report in &reports {
+            for tolerance in &tolerances {
+                let removed = super::dampen(report, tolerance);
+                assert_eq!(removed.as_ref().map(Vec::len), fewest_removals(report, tolerance), "{:?}", report);
+                assert_eq!(super::verdict(report, tolerance).is_safe(), removed.as_deref() == Some(&[]), "{:?}", report);
+
+                if let Some(removed) = removed {
+                    let kept: Vec<i32> = (0..report.len()).filter(|i| !removed.contains(i)).map(|i| report[i]).collect();
+                    assert_eq!(super::dampen(&kept, &Tolerance { removals: 0, ..*tolerance }), Some(vec![]));
+                }
+            }
+        }
+    }
+}
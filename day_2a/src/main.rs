@@ -1,89 +1,42 @@
-fn parse_report(report: &str) -> Vec<i32> {
-    // split by space
-    report.split_whitespace()
-        // parse each element to i32
-        .map(|x| x.parse().unwrap())
-        // collect to Vec<i32>
-        .collect()
-}
-
-enum Bool3 {
-    True,
-    False,
-    Unknown,
-}
-
-fn report_is_safe(report: &Vec<i32>) -> bool {
-    let mut prev = report[0];
-    let mut increasing = Bool3::Unknown;
-
-    for i in 1..report.len() {
-        let current = report[i];
-
-        if (current-prev).abs() > 3 || current == prev {
-            return false;
-        }
-
-        match increasing {
-            Bool3::Unknown => {
-                if current > prev {
-                    increasing = Bool3::True;
-                } else {
-                    increasing = Bool3::False;
-                }
-            },
-            Bool3::True => {
-                if current < prev {
-                    return false;
-                }
-            },
-            Bool3::False => {
-                if current > prev {
-                    return false;
-                }
-            },
-        }
-
-        prev = current;
-    }
-
-    return true;
-}
-
-fn report_is_safe_by_removing(report: &Vec<i32>) -> bool {
-    if report_is_safe(&report) {
-        return true;
-    }
-
-    for i in 0..report.len() {
-        let mut new_report = report.clone();
-        new_report.remove(i);
-
-        if report_is_safe(&new_report) {
-            return true;
-        }
-    }
-
-    return false;
-}
+use common::or_exit;
+use day_2a::Tolerance;
 
 fn main() {
     // read from input.txt
     let input = std::fs::read_to_string("input.txt").unwrap();
 
-    // split by new line
-    let lines = input.lines();
-
-    // collect reports
-    let reports: Vec<Vec<i32>> = lines.map(|line| parse_report(line)).collect();
-
-    let mut safe_reports = 0;
-    // iterate over reports
-    for report in reports {
-        if report_is_safe_by_removing(&report) {
-            safe_reports += 1;
+    // `day_2a [--report] <removals> [<min step> <max step>]` counts the safe reports under another
+    // policy, or with --report shows the verdict on every report
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let report = args.iter().any(|arg| arg == "--report");
+    args.retain(|arg| arg != "--report");
+
+    let args: Vec<usize> = args
+        .iter()
+        .map(|arg| arg.parse().unwrap_or_else(|_| {
+            eprintln!("error: expected a number, found {}", arg);
+            std::process::exit(1);
+        }))
+        .collect();
+
+    let tolerance = match args.as_slice() {
+        [] if report => Tolerance::PART_2,
+        [] => {
+            println!("{}", or_exit(day_2a::part_1(&input), &input));
+            println!("{}", or_exit(day_2a::part_2(&input), &input));
+            return;
         }
-    }
+        &[removals] => Tolerance { removals, ..Tolerance::PART_1 },
+        &[removals, min_step, max_step] => Tolerance { removals, min_step: min_step as i32, max_step: max_step as i32 },
+        _ => {
+            eprintln!("usage: day_2a [--report] [<removals> [<min step> <max step>]]");
+            std::process::exit(1);
+        }
+    };
 
-    println!("{}", safe_reports);
+    if report {
+        print!("{}", or_exit(day_2a::report(&input, &tolerance), &input));
+    } else {
+        println!("{}", or_exit(day_2a::count_dampened(&input, &tolerance), &input));
+    }
 }
@@ -0,0 +1,421 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::{Range, RangeInclusive};
+use std::time::{Duration, Instant};
+
+use common::ParseError;
+
+/// The most operands any instruction can take.
+pub const MAX_ARITY: usize = 4;
+
+/// What the instructions work on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub accumulator: i64,
+    pub enabled: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self { accumulator: 0, enabled: true }
+    }
+}
+
+/// What an instruction does, given its operands.
+pub type Effect = fn(&mut State, &[i64]);
+
+/// An instruction the scanner recognises: `name(a,b,...)` with `arity` operands, each written
+/// with a number of digits in `digits`.
+#[derive(Debug, Clone)]
+pub struct Definition {
+    pub name: &'static str,
+    pub arity: usize,
+    pub digits: RangeInclusive<usize>,
+    pub effect: Effect,
+}
+
+impl Definition {
+    pub const MUL: Self = Self::new("mul", 2, |state, ops| {
+        if state.enabled {
+            state.accumulator += ops[0] * ops[1];
+        }
+    });
+    pub const DO: Self = Self::new("do", 0, |state, _| state.enabled = true);
+    pub const DONT: Self = Self::new("don't", 0, |state, _| state.enabled = false);
+    pub const ADD: Self = Self::new("add", 2, |state, ops| {
+        if state.enabled {
+            state.accumulator += ops[0] + ops[1];
+        }
+    });
+    pub const SUB: Self = Self::new("sub", 2, |state, ops| {
+        if state.enabled {
+            state.accumulator += ops[0] - ops[1];
+        }
+    });
+    pub const RESET: Self = Self::new("reset", 0, |state, _| state.accumulator = 0);
+
+    /// Every instruction that comes with the interpreter.
+    pub const BUILT_IN: [Self; 6] = [Self::MUL, Self::DO, Self::DONT, Self::ADD, Self::SUB, Self::RESET];
+
+    /// An instruction whose operands have 1 to 3 digits, like the puzzle's.
+    pub const fn new(name: &'static str, arity: usize, effect: Effect) -> Self {
+        Self { name, arity, digits: 1..=3, effect }
+    }
+
+    pub fn built_in(name: &str) -> Option<Self> {
+        Self::BUILT_IN.into_iter().find(|definition| definition.name == name)
+    }
+
+    /// The most bytes the instruction can take up.
+    pub fn longest(&self) -> usize {
+        self.name.len() + 2 + self.arity * self.digits.end() + self.arity.saturating_sub(1)
+    }
+}
+
+/// Part 1 only multiplies.
+pub const PART_1: [Definition; 1] = [Definition::MUL];
+/// Part 2 also follows do() and don't().
+pub const PART_2: [Definition; 3] = [Definition::MUL, Definition::DO, Definition::DONT];
+
+/// An instruction that survived the corruption: which definition in the table it is, and its
+/// operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: usize,
+    arity: usize,
+    operands: [i64; MAX_ARITY],
+}
+
+impl Instruction {
+    pub fn operands(&self) -> &[i64] {
+        &self.operands[..self.arity]
+    }
+}
+
+/// An instruction and the bytes of memory it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub instruction: Instruction,
+    pub span: Range<usize>,
+}
+
+/// The instructions from `table` in a piece of memory, in order. Each byte is only looked at
+/// as often as there are instructions to try, so this is a single pass.
+pub struct Tokens<'a> {
+    memory: &'a [u8],
+    table: &'a [Definition],
+    at: usize,
+    // no instruction starts at or after this, though one can run past it
+    end: usize,
+    // the bytes an instruction can start with, so the rest can be skipped without trying them all
+    starts: [bool; 256],
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(memory: &'a str, table: &'a [Definition]) -> Self {
+        Self::from_bytes(memory.as_bytes(), table)
+    }
+
+    /// Memory dumps don't have to be text.
+    pub fn from_bytes(memory: &'a [u8], table: &'a [Definition]) -> Self {
+        Self::until(memory, table, memory.len())
+    }
+
+    fn until(memory: &'a [u8], table: &'a [Definition], end: usize) -> Self {
+        assert!(table.iter().all(|d| d.arity <= MAX_ARITY), "instructions take at most {} operands", MAX_ARITY);
+        let mut starts = [false; 256];
+        for definition in table {
+            if let Some(&first) = definition.name.as_bytes().first() {
+                starts[first as usize] = true;
+            }
+        }
+
+        Self { memory, table, at: 0, end, starts }
+    }
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.at < self.end {
+            let start = self.at;
+            if !self.starts[self.memory[start] as usize] {
+                self.at += 1;
+                continue;
+            }
+
+            let found = self.table.iter().enumerate().find_map(|(op, definition)| {
+                instruction_at(self.memory, start, op, definition)
+            });
+
+            match found {
+                Some((instruction, end)) => {
+                    self.at = end;
+                    return Some(Token { instruction, span: start..end });
+                }
+                None => self.at += 1,
+            }
+        }
+
+        None
+    }
+}
+
+// `definition` starting at `at`, and where it ends
+fn instruction_at(memory: &[u8], at: usize, op: usize, definition: &Definition) -> Option<(Instruction, usize)> {
+    // name(X,Y,...)
+    if !memory[at..].starts_with(definition.name.as_bytes()) {
+        return None;
+    }
+    let mut at = byte(memory, at + definition.name.len(), b'(')?;
+
+    let mut operands = [0; MAX_ARITY];
+    for (i, operand) in operands.iter_mut().take(definition.arity).enumerate() {
+        if i > 0 {
+            at = byte(memory, at, b',')?;
+        }
+        (*operand, at) = number(memory, at, &definition.digits)?;
+    }
+    let at = byte(memory, at, b')')?;
+
+    Some((Instruction { op, arity: definition.arity, operands }, at))
+}
+
+// a number with an allowed count of digits starting at `at`, and where it ends
+fn number(memory: &[u8], at: usize, digits: &RangeInclusive<usize>) -> Option<(i64, usize)> {
+    let count = memory[at..].iter().take(digits.end() + 1).take_while(|b| b.is_ascii_digit()).count();
+    if !digits.contains(&count) {
+        return None;
+    }
+
+    let value = memory[at..at + count].iter().fold(0, |n, b| n * 10 + (b - b'0') as i64);
+    Some((value, at + count))
+}
+
+fn byte(memory: &[u8], at: usize, wanted: u8) -> Option<usize> {
+    (memory.get(at) == Some(&wanted)).then_some(at + 1)
+}
+
+/// Runs instructions from a table one at a time.
+#[derive(Debug, Clone)]
+pub struct Interpreter<'a> {
+    table: &'a [Definition],
+    state: State,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(table: &'a [Definition]) -> Self {
+        Self { table, state: State::default() }
+    }
+
+    pub fn execute(&mut self, instruction: &Instruction) {
+        (self.table[instruction.op].effect)(&mut self.state, instruction.operands());
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+}
+
+/// Runs every instruction from `table` in `memory`, returning the accumulator.
+pub fn run(memory: &str, table: &[Definition]) -> i64 {
+    let mut interpreter = Interpreter::new(table);
+    for token in Tokens::new(memory, table) {
+        interpreter.execute(&token.instruction);
+    }
+
+    interpreter.state().accumulator
+}
+
+/// How much memory a streamed scan has read, and how long it has taken.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Throughput {
+    pub bytes: u64,
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    pub fn bytes_per_second(&self) -> f64 {
+        self.bytes as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+impl fmt::Display for Throughput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const MIB: f64 = 1024.0 * 1024.0;
+        write!(
+            f,
+            "{:.1} MiB in {:.2?} ({:.1} MiB/s)",
+            self.bytes as f64 / MIB,
+            self.elapsed,
+            self.bytes_per_second() / MIB
+        )
+    }
+}
+
+/// Scans memory from `reader` a buffer at a time, calling `on_token` for every instruction with
+/// its span counted from the start of the stream. Only the end of a buffer that could hold the
+/// start of an unfinished instruction is kept for the next one, so memory use doesn't grow with
+/// the size of the dump.
+pub fn stream(
+    mut reader: impl BufRead,
+    table: &[Definition],
+    mut on_token: impl FnMut(Token),
+) -> io::Result<Throughput> {
+    const REPORT_EVERY: u64 = 256 * 1024 * 1024;
+
+    let start = Instant::now();
+    let longest = table.iter().map(Definition::longest).max().unwrap_or(0);
+
+    // the unscanned end of the last buffer, then the next buffer, and where it all starts in the
+    // stream
+    let mut window: Vec<u8> = Vec::new();
+    let mut offset = 0;
+    let mut read = 0u64;
+
+    loop {
+        let buffer = reader.fill_buf()?;
+        let done = buffer.is_empty();
+        window.extend_from_slice(buffer);
+        let len = buffer.len();
+        reader.consume(len);
+
+        // an instruction starting any later could carry on into the next buffer
+        let end = if done { window.len() } else { window.len().saturating_sub(longest) };
+        let mut tokens = Tokens::until(&window, table, end);
+        for token in tokens.by_ref() {
+            let span = token.span.start + offset..token.span.end + offset;
+            on_token(Token { span, ..token });
+        }
+
+        let scanned = tokens.at;
+        window.drain(..scanned);
+        offset += scanned;
+
+        if read / REPORT_EVERY != (read + len as u64) / REPORT_EVERY {
+            common::diag!(2, "{}", Throughput { bytes: read + len as u64, elapsed: start.elapsed() });
+        }
+        read += len as u64;
+
+        if done {
+            return Ok(Throughput { bytes: read, elapsed: start.elapsed() });
+        }
+    }
+}
+
+/// Runs every instruction from `table` in the memory from `reader`, returning the accumulator and
+/// how quickly the memory was read.
+pub fn run_reader(reader: impl BufRead, table: &[Definition]) -> io::Result<(i64, Throughput)> {
+    let mut interpreter = Interpreter::new(table);
+    let throughput = stream(reader, table, |token| interpreter.execute(&token.instruction))?;
+
+    Ok((interpreter.state().accumulator, throughput))
+}
+
+// the scanner reads the memory as it goes, so there is nothing to parse up front
+// corrupted memory can hold anything, so there is nothing that can fail to parse, but finding
+// the instructions in it is most of the work
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    std::hint::black_box(Tokens::new(input, &PART_2).count());
+    Ok(())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    Ok(run(input, &PART_1))
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    Ok(run(input, &PART_2))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+
+    use super::{Definition, Tokens, PART_1, PART_2};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    // each instruction as its name and operands
+    fn instructions<'a>(memory: &str, table: &'a [Definition]) -> Vec<(&'a str, Vec<i64>)> {
+        Tokens::new(memory, table)
+            .map(|t| (table[t.instruction.op].name, t.instruction.operands().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 161);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE_2).unwrap(), 48);
+    }
+
+    #[test]
+    fn tokens_of_the_example() {
+        let tokens: Vec<_> = Tokens::new(EXAMPLE_2, &PART_2).collect();
+
+        assert_eq!(tokens.len(), 6);
+        assert_eq!(tokens[0].span, 1..9);
+        assert_eq!(tokens[0].instruction.operands(), [2, 4]);
+        assert_eq!(PART_2[tokens[1].instruction.op].name, "don't");
+        assert_eq!(tokens[1].span, 20..27);
+        assert_eq!(&EXAMPLE_2[tokens[5].span.clone()], "mul(8,5)");
+    }
+
+    #[test]
+    fn operands_and_the_end_of_memory() {
+        assert_eq!(instructions("mul(123,4)mul(1234,5)mul(,5)mul(6,7", &PART_1), [("mul", vec![123, 4])]);
+        assert_eq!(instructions("xmul(2,", &PART_1), []);
+        assert_eq!(instructions("mul(4", &PART_1), []);
+        assert_eq!(instructions("don't(do()", &PART_2), [("do", vec![])]);
+    }
+
+    #[test]
+    fn extra_instructions() {
+        let table = Definition::BUILT_IN;
+        let memory = "add(2,3)mul(2,2)sub(1,10)!reset()add(1,1)don't()add(5,5)do()sub(7,3)";
+
+        assert_eq!(instructions("reset()add(1,2,3)sub(4,5)", &table), [("reset", vec![]), ("sub", vec![4, 5])]);
+        assert_eq!(super::run(memory, &table), 6);
+
+        let wide = [Definition { digits: 1..=5, ..Definition::ADD }];
+        assert_eq!(super::run("add(12345,1)add(123456,1)", &wide), 12346);
+    }
+
+    #[test]
+    fn streaming_across_buffers() {
+        let table = Definition::BUILT_IN;
+        let memory = format!("{}add(99,1)reset()sub(5,2)mul(999,999){}", INPUT, EXAMPLE_2);
+        let whole: Vec<_> = Tokens::new(&memory, &table).collect();
+
+        // every buffer size splits some instruction down the middle
+        for capacity in [1, 2, 3, 7, 13, 64, 4096] {
+            let reader = BufReader::with_capacity(capacity, memory.as_bytes());
+            let mut tokens = Vec::new();
+            let throughput = super::stream(reader, &table, |token| tokens.push(token)).unwrap();
+
+            assert_eq!(tokens, whole, "buffers of {} bytes", capacity);
+            assert_eq!(throughput.bytes, memory.len() as u64);
+        }
+
+        let reader = BufReader::with_capacity(5, INPUT.as_bytes());
+        assert_eq!(super::run_reader(reader, &PART_2).unwrap().0, super::part_2(INPUT).unwrap());
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
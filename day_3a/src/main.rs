@@ -1,73 +1,52 @@
-fn main() {
-    // read text from input.txt
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let mut sum = 0;
-    let mut cur_idx = 0;
-    let mut enabled = true;
-    while cur_idx < input.len() {
-        let cur_char = input.chars().nth(cur_idx).unwrap();
-
-        // look for mul(X,Y)
-
+use std::fs::File;
+use std::io::BufReader;
 
-        if enabled && cur_char == 'm' &&
-            input.chars().nth(cur_idx+1) == Some('u') &&
-            input.chars().nth(cur_idx+2) == Some('l') &&
-            input.chars().nth(cur_idx+3) == Some('(') {
-            let mut idx = cur_idx + 4;
-            let mut x = 0;
-            let mut y = 0;
-            // look for number, until , or non number
-            while input.chars().nth(idx).unwrap().is_digit(10) {
-                x = x * 10 + input.chars().nth(idx).unwrap().to_digit(10).unwrap();
-                idx += 1;
-            }
+use common::or_exit;
+use day_3a::{Definition, PART_2};
 
-            // skip ,
-            if input.chars().nth(idx).unwrap() != ',' {
-                cur_idx = idx + 1;
-                continue;
-            }
-
-            idx += 1;
-
-            // look for number, until )
-            while input.chars().nth(idx).unwrap().is_digit(10) {
-                y = y * 10 + input.chars().nth(idx).unwrap().to_digit(10).unwrap();
-                idx += 1;
-            }
+fn main() {
+    // `day_3a [--file <dump>] [<instruction>...]` streams a memory dump of any size through the
+    // named instructions (or part 2's), reporting how fast it went
+    let mut file = None;
+    let mut table: Vec<Definition> = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--file" {
+            file = args.next();
+            continue;
+        }
 
-            // skip )
-            if input.chars().nth(idx).unwrap() != ')' {
-                cur_idx = idx + 1;
-                continue;
-            }
+        table.push(Definition::built_in(&arg).unwrap_or_else(|| {
+            let names: Vec<&str> = Definition::BUILT_IN.iter().map(|d| d.name).collect();
+            eprintln!("error: no instruction called {}, try one of {}", arg, names.join(", "));
+            std::process::exit(1);
+        }));
+    }
 
-            sum += x * y;
-            cur_idx = idx + 1;
-        } else if cur_char == 'd' &&
-            input.chars().nth(cur_idx+1) == Some('o') &&
-            input.chars().nth(cur_idx+2) == Some('(') &&
-            input.chars().nth(cur_idx+3) == Some(')')  {
+    if file.is_none() && table.is_empty() {
+        // read text from input.txt
+        let input = std::fs::read_to_string("input.txt").unwrap();
 
-            enabled = true;
-            cur_idx += 3;
-        } else if cur_char == 'd' &&
-            input.chars().nth(cur_idx+1) == Some('o') &&
-            input.chars().nth(cur_idx+2) == Some('n') &&
-            input.chars().nth(cur_idx+3) == Some('\'') &&
-            input.chars().nth(cur_idx+4) == Some('t') &&
-            input.chars().nth(cur_idx+5) == Some('(') &&
-            input.chars().nth(cur_idx+6) == Some(')') {
+        println!("Sum: {}", or_exit(day_3a::part_1(&input), &input));
+        println!("Sum: {}", or_exit(day_3a::part_2(&input), &input));
+        return;
+    }
 
-            enabled = false;
+    if table.is_empty() {
+        table = PART_2.to_vec();
+    }
 
-            cur_idx += 6;
-        } else {
-            cur_idx += 1;
+    let path = file.unwrap_or_else(|| "input.txt".to_string());
+    let result = File::open(&path).and_then(|f| day_3a::run_reader(BufReader::with_capacity(1 << 20, f), &table));
+    match result {
+        Ok((accumulator, throughput)) => {
+            println!("Accumulator: {}", accumulator);
+            eprintln!("{}", throughput);
+        }
+        Err(e) => {
+            eprintln!("error: could not read {}: {}", path, e);
+            std::process::exit(1);
         }
     }
-
-    println!("Sum: {}", sum);
 }
@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+use common::ParseError;
+use grid::{Grid, Point, NEIGHBOURS_8};
+
+/// Which way a template was turned to fit: the step in the grid for one letter along a row of
+/// the template, and for one row down it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub across: Point,
+    pub down: Point,
+}
+
+impl Orientation {
+    fn apply(self, column: isize, row: isize) -> Point {
+        self.across * column + self.down * row
+    }
+
+    // a word only has one row, so it can also run diagonally
+    fn words() -> impl Iterator<Item = Self> {
+        // clockwise from east
+        (0..8).map(|i| {
+            let across = NEIGHBOURS_8[(i + 2) % 8];
+            Self { across, down: clockwise(across) }
+        })
+    }
+
+    // every rotation, then every rotation of the mirror image
+    fn shapes() -> impl Iterator<Item = Self> {
+        [clockwise, anticlockwise].into_iter().flat_map(|turn_down| {
+            std::iter::successors(Some(Point::new(1, 0)), |&across| Some(clockwise(across)))
+                .take(4)
+                .map(move |across| Self { across, down: turn_down(across) })
+        })
+    }
+}
+
+fn clockwise(p: Point) -> Point {
+    Point::new(-p.y, p.x)
+}
+
+fn anticlockwise(p: Point) -> Point {
+    Point::new(p.y, -p.x)
+}
+
+fn compass(p: Point) -> &'static str {
+    match (p.x, p.y) {
+        (0, -1) => "N",
+        (1, -1) => "NE",
+        (1, 0) => "E",
+        (1, 1) => "SE",
+        (0, 1) => "S",
+        (-1, 1) => "SW",
+        (-1, 0) => "W",
+        _ => "NW",
+    }
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "across {} down {}", compass(self.across), compass(self.down))
+    }
+}
+
+/// A small pattern of letters to look for, with rows split by `/` and `.` matching anything, like
+/// `M.S/.A./M.S`. A pattern with one row is a word, and is looked for in all eight directions;
+/// anything bigger is looked for turned and flipped every way.
+#[derive(Debug, Clone)]
+pub struct Template {
+    variants: Vec<Variant>,
+}
+
+#[derive(Debug, Clone)]
+struct Variant {
+    orientation: Orientation,
+    // where each letter is from where the first letter of the pattern lands
+    cells: Vec<(Point, char)>,
+}
+
+/// Somewhere a template fits: where the top left of the pattern as written landed, which way the
+/// pattern was turned, and the cells its letters cover. `variant` numbers the different ways the
+/// template can be turned, from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub position: Point,
+    pub orientation: Orientation,
+    pub variant: usize,
+    pub cells: Vec<Point>,
+}
+
+impl Template {
+    /// `None` if the pattern has no letters in it.
+    pub fn new(pattern: &str) -> Option<Self> {
+        let rows: Vec<&str> = pattern.split('/').collect();
+        let letters: Vec<(isize, isize, char)> = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c != '.')
+                    .map(move |(column, c)| (column as isize, row as isize, c))
+            })
+            .collect();
+
+        if letters.is_empty() {
+            return None;
+        }
+
+        let orientations: Vec<Orientation> = if rows.len() == 1 {
+            Orientation::words().collect()
+        } else {
+            Orientation::shapes().collect()
+        };
+
+        // a symmetric pattern looks the same turned some ways, and would match the same cells
+        // more than once
+        let mut seen = HashSet::new();
+        let mut variants = Vec::new();
+        for orientation in orientations {
+            let cells: Vec<(Point, char)> = letters
+                .iter()
+                .map(|&(column, row, c)| (orientation.apply(column, row), c))
+                .collect();
+
+            let corner = Point::new(
+                cells.iter().map(|(p, _)| p.x).min().unwrap(),
+                cells.iter().map(|(p, _)| p.y).min().unwrap(),
+            );
+            let mut shape: Vec<(Point, char)> = cells.iter().map(|&(p, c)| (p - corner, c)).collect();
+            shape.sort();
+
+            if seen.insert(shape) {
+                variants.push(Variant { orientation, cells });
+            }
+        }
+
+        Some(Self { variants })
+    }
+
+    /// Every place the template fits, in reading order.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Match> {
+        let mut found = Vec::new();
+
+        for position in grid.points() {
+            for (index, variant) in self.variants.iter().enumerate() {
+                let fits = variant.cells.iter().all(|&(offset, c)| grid.get(position + offset) == Some(&c));
+
+                if fits {
+                    found.push(Match {
+                        position,
+                        orientation: variant.orientation,
+                        variant: index,
+                        cells: variant.cells.iter().map(|&(offset, _)| position + offset).collect(),
+                    });
+                }
+            }
+        }
+
+        found
+    }
+}
+
+/// The word search's letters.
+pub fn generate_map(input: &str) -> Result<Grid<char>, ParseError> {
+    // any letter can turn up in the puzzle, only XMAS matters
+    Grid::parse(input, |_, c| Some(c), "a letter")
+}
+
+/// Everywhere `template` fits in the word search.
+pub fn find_matches(input: &str, template: &Template) -> Result<Vec<Match>, ParseError> {
+    Ok(template.find(&generate_map(input)?))
+}
+
+/// How `render` shows the matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// only the matched letters, with `.` everywhere else
+    Matched,
+    /// every letter, with matched ones coloured by which way the template was turned, and
+    /// letters that more than one match share in reverse video
+    Colour,
+}
+
+// red, green, yellow, blue, magenta, cyan, and their bright versions
+const COLOURS: [u8; 12] = [31, 32, 33, 34, 35, 36, 91, 92, 93, 94, 95, 96];
+
+/// The word search with `matches` picked out.
+pub fn render(grid: &Grid<char>, matches: &[Match], highlight: Highlight) -> String {
+    // the first match over each cell, and how many there are
+    let mut covered: Grid<Option<(usize, usize)>> = Grid::new(grid.width(), grid.height(), None);
+    for m in matches {
+        for &p in &m.cells {
+            covered[p].get_or_insert((m.variant, 0)).1 += 1;
+        }
+    }
+
+    let mut out = String::new();
+    for (p, &c) in grid.iter() {
+        match (highlight, covered[p]) {
+            (Highlight::Matched, Some(_)) => out.push(c),
+            (Highlight::Matched, None) => out.push('.'),
+            (Highlight::Colour, Some((variant, count))) => {
+                let colour = COLOURS[variant % COLOURS.len()];
+                let reverse = if count > 1 { ";7" } else { "" };
+                out += &format!("\x1b[{}{}m{}\x1b[0m", colour, reverse, c);
+            }
+            (Highlight::Colour, None) => out.push(c),
+        }
+
+        if p.x as usize == grid.width() - 1 {
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// One line per match, numbered from 1, with where it is, which way it was turned and the
+/// coordinates (x, y) of each of its letters.
+pub fn listing(matches: &[Match]) -> String {
+    let point = |p: Point| format!("({}, {})", p.x, p.y);
+
+    matches
+        .iter()
+        .enumerate()
+        .map(|(i, m)| {
+            let cells: Vec<String> = m.cells.iter().map(|&p| point(p)).collect();
+            format!("{}: {} {}: {}\n", i + 1, point(m.position), m.orientation, cells.join(" "))
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    generate_map(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    Ok(find_matches(input, &Template::new("XMAS").unwrap())?.len())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    // two MAS's crossing
+    let grid = generate_map(input)?;
+    let found = Template::new("M.S/.A./M.S").unwrap().find(&grid);
+
+    if common::verbosity() >= 1 {
+        eprint!("{}", render(&grid, &found, Highlight::Matched));
+    }
+
+    Ok(found.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::Point;
+
+    use super::{Highlight, Template};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 18);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 9);
+    }
+
+    #[test]
+    fn templates_turned_every_way() {
+        let grid = super::generate_map("ABA\nBCB\nABA\n").unwrap();
+        let count = |pattern| Template::new(pattern).unwrap().find(&grid).len();
+
+        // a palindrome reads the same both ways, so only one of each pair counts
+        assert_eq!(count("ABA"), 4);
+        assert_eq!(count("AB"), 8);
+        assert_eq!(count("A.A/.C./A.A"), 1);
+        assert_eq!(count("AB/.C"), 8);
+        assert!(Template::new("../..").is_none());
+    }
+
+    #[test]
+    fn orientation_of_a_match() {
+        let grid = super::generate_map("S..\n.A.\n..M\n").unwrap();
+        let found = Template::new("MAS").unwrap().find(&grid);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].position, Point::new(2, 2));
+        assert_eq!(found[0].orientation.to_string(), "across NW down NE");
+        assert_eq!(found[0].cells, [Point::new(2, 2), Point::new(1, 1), Point::new(0, 0)]);
+    }
+
+    #[test]
+    fn rendering_matches() {
+        let grid = super::generate_map("XMASX\nSAMXM\n").unwrap();
+        let found = Template::new("XMAS").unwrap().find(&grid);
+
+        assert_eq!(super::render(&grid, &found, Highlight::Matched), "XMAS.\nSAMX.\n");
+        assert_eq!(super::listing(&found[..1]), "1: (0, 0) across E down S: (0, 0) (1, 0) (2, 0) (3, 0)\n");
+
+        // the X at (3, 1) starts two matches, and the M at (4, 1) ends two
+        let found = Template::new("XM").unwrap().find(&grid);
+        let coloured = super::render(&grid, &found, Highlight::Colour);
+        assert_eq!(found.len(), 4);
+        assert_eq!(coloured.matches("\x1b[0m").count(), 6);
+        assert_eq!(coloured.matches(";7m").count(), 2);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
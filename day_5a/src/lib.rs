@@ -0,0 +1,888 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{self, Display};
+
+use common::{next_field, ParseError};
+
+#[derive(Debug)]
+struct Rules(Vec<Constraint>);
+
+#[derive(Debug, Clone)]
+struct Update<'a> {
+    pages: Vec<i32>,
+    // the line it was read from, for errors
+    line: &'a str,
+}
+
+fn read_input(input: &str) -> Result<(Rules, Vec<Update<'_>>), ParseError> {
+    /*
+    layout:
+    1|2
+    3|4
+    first 5
+    adjacent 6,7
+    // end of rules section (this comment is not included in the input. instead its just an empty line)
+
+    1,2,3,4
+     */
+
+    let mut rules = Vec::new();
+    let mut updates = Vec::new();
+
+    let mut is_rules = true;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            is_rules = false;
+            continue;
+        }
+
+        if is_rules {
+            rules.push(Constraint::parse(input, line)?);
+        } else {
+            let pages = line.split(',').map(|x| common::parse(input, x, "a page number")).collect::<Result<_, _>>()?;
+            updates.push(Update { pages, line });
+        }
+    }
+
+    Ok((Rules(rules), updates))
+}
+
+/// Something an update's order has to keep. Like the puzzle's rules, a constraint only counts
+/// when every page it names is in the update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// `a|b`: a comes somewhere before b
+    Before(i32, i32),
+    /// `first a`
+    First(i32),
+    /// `last a`
+    Last(i32),
+    /// `adjacent a,b`: a and b are next to each other, either way round
+    Adjacent(i32, i32),
+    /// `within n a,b`: there are at most n pages between a and b, either way round
+    Within(usize, i32, i32),
+}
+
+impl Constraint {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let page = |s: &str| common::parse(input, s, "a page number");
+        let pair = |s: &str, separator: &str| -> Result<(i32, i32), ParseError> {
+            let mut parts = s.split(separator);
+            let a = page(next_field(input, s, &mut parts, "a page number")?)?;
+            let b = page(next_field(input, s, &mut parts, &format!("`{}` and a second page number", separator))?)?;
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::at(input, extra, "the end of the rule"));
+            }
+            Ok((a, b))
+        };
+
+        if let Some(rest) = line.strip_prefix("first ") {
+            Ok(Self::First(page(rest)?))
+        } else if let Some(rest) = line.strip_prefix("last ") {
+            Ok(Self::Last(page(rest)?))
+        } else if let Some(rest) = line.strip_prefix("adjacent ") {
+            let (a, b) = pair(rest, ",")?;
+            Ok(Self::Adjacent(a, b))
+        } else if let Some(rest) = line.strip_prefix("within ") {
+            let (most, pages) = rest
+                .split_once(' ')
+                .ok_or_else(|| ParseError::after(input, rest, "a space and two pages separated by `,`"))?;
+            let (a, b) = pair(pages, ",")?;
+            Ok(Self::Within(common::parse(input, most, "a number of pages")?, a, b))
+        } else {
+            let (a, b) = pair(line, "|")?;
+            Ok(Self::Before(a, b))
+        }
+    }
+
+    // whether the update has every page the constraint names, which is when it counts
+    fn applies(&self, pages: &[i32]) -> bool {
+        match *self {
+            Self::Before(a, b) | Self::Adjacent(a, b) | Self::Within(_, a, b) => pages.contains(&a) && pages.contains(&b),
+            Self::First(a) | Self::Last(a) => pages.contains(&a),
+        }
+    }
+
+    /// Whether an order keeps the constraint, given where each page in it is.
+    pub fn holds(&self, index: &HashMap<i32, usize>, len: usize) -> bool {
+        let at = |page| index.get(&page).copied();
+
+        match *self {
+            Self::Before(a, b) => match (at(a), at(b)) {
+                (Some(a), Some(b)) => a < b,
+                _ => true,
+            },
+            Self::First(a) => at(a).is_none_or(|a| a == 0),
+            Self::Last(a) => at(a).is_none_or(|a| a + 1 == len),
+            Self::Adjacent(a, b) => Self::Within(0, a, b).holds(index, len),
+            Self::Within(most, a, b) => match (at(a), at(b)) {
+                (Some(a), Some(b)) => a.abs_diff(b) <= most + 1,
+                _ => true,
+            },
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Before(a, b) => write!(f, "{}|{}", a, b),
+            Self::First(a) => write!(f, "first {}", a),
+            Self::Last(a) => write!(f, "last {}", a),
+            Self::Adjacent(a, b) => write!(f, "adjacent {},{}", a, b),
+            Self::Within(most, a, b) => write!(f, "within {} {},{}", most, a, b),
+        }
+    }
+}
+
+/// Rules that can't all be kept: each page has to come before the next, and the last one before
+/// the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<i32>);
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for page in &self.0 {
+            write!(f, "{} -> ", page)?;
+        }
+        write!(f, "{}", self.0[0])
+    }
+}
+
+impl Rules {
+    // the puzzle's kind of rule
+    fn pairs(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.0.iter().filter_map(|c| match *c {
+            Constraint::Before(a, b) => Some((a, b)),
+            _ => None,
+        })
+    }
+
+    // the pages each page has to come before
+    fn successors(&self) -> HashMap<i32, Vec<i32>> {
+        let mut after: HashMap<i32, Vec<i32>> = HashMap::new();
+        for (a, b) in self.pairs() {
+            after.entry(a).or_default().push(b);
+        }
+        after
+    }
+
+    fn keeps(&self, pages: &[i32]) -> bool {
+        keeps(&self.0, pages)
+    }
+
+    // the pages in an order that keeps the rules, or why there isn't one
+    fn fix(&self, after: &HashMap<i32, Vec<i32>>, pages: &[i32]) -> Result<Vec<i32>, String> {
+        if self.0.iter().all(|c| matches!(c, Constraint::Before(..)) || !c.applies(pages)) {
+            // the puzzle's rules only need a topological sort, and so does any update none of
+            // the other constraints apply to
+            sort_pages(after, pages).map_err(|cycle| format!("{} goes round in a circle", cycle))
+        } else {
+            solve(&self.0, pages).map(|solution| solution.order).map_err(|e| e.to_string())
+        }
+    }
+}
+
+// a topological sort of the pages, using only the rules between pages in the update. when more
+// than one page could go next the one that came first goes first, so pages that are already in
+// order stay where they are
+fn sort_pages(after: &HashMap<i32, Vec<i32>>, pages: &[i32]) -> Result<Vec<i32>, Cycle> {
+    let index: HashMap<i32, usize> = pages.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+
+    let mut next = vec![Vec::new(); pages.len()];
+    let mut before = vec![Vec::new(); pages.len()];
+    for (i, page) in pages.iter().enumerate() {
+        for later in after.get(page).into_iter().flatten() {
+            if let Some(&j) = index.get(later) {
+                next[i].push(j);
+                before[j].push(i);
+            }
+        }
+    }
+
+    let mut waiting_on: Vec<usize> = before.iter().map(Vec::len).collect();
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..pages.len()).filter(|&i| waiting_on[i] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(pages.len());
+
+    while let Some(Reverse(i)) = ready.pop() {
+        order.push(pages[i]);
+        for &j in &next[i] {
+            waiting_on[j] -= 1;
+            if waiting_on[j] == 0 {
+                ready.push(Reverse(j));
+            }
+        }
+    }
+
+    if order.len() == pages.len() {
+        return Ok(order);
+    }
+
+    // every page left is waiting on another page that's left, so walking back through them
+    // has to come round to one already seen
+    let mut seen = vec![None; pages.len()];
+    let mut path = Vec::new();
+    let mut at = (0..pages.len()).find(|&i| waiting_on[i] > 0).unwrap();
+    while seen[at].is_none() {
+        seen[at] = Some(path.len());
+        path.push(at);
+        at = *before[at].iter().find(|&&i| waiting_on[i] > 0).unwrap();
+    }
+
+    // the walk went backwards, and the cycle reads best from the first of its pages in the update
+    let mut cycle: Vec<usize> = path[seen[at].unwrap()..].to_vec();
+    cycle.reverse();
+    let first = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(first);
+
+    Err(Cycle(cycle.iter().map(|&i| pages[i]).collect()))
+}
+
+/// A way to put an update in order, and the pages that had to be moved (taken out and put back
+/// somewhere else) to get it, in the order the update had them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub order: Vec<i32>,
+    pub moved: Vec<i32>,
+}
+
+/// Why `solve` couldn't put an update in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsolvable {
+    Infeasible,
+    TooLong,
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Infeasible => write!(f, "no order keeps every rule"),
+            Self::TooLong => write!(f, "there are too many orders to search through"),
+        }
+    }
+}
+
+// the longest update `solve` searches, which is longer than any in the puzzle
+const MAX_PAGES: usize = 24;
+// and the most states it remembers before giving up
+const MAX_STATES: usize = 1 << 18;
+
+/// Puts `pages` in an order that keeps every constraint, moving as few pages as it can. This
+/// searches through the orders, only looking for ones that move no more pages than a quick guess
+/// does. That's quick when the constraints pin the order down (the puzzle has a rule for every
+/// pair of pages) or the guess is good, but a long update with few constraints can still have
+/// too many orders to get through, and then it gives up with `TooLong`.
+pub fn solve(constraints: &[Constraint], pages: &[i32]) -> Result<Solution, Unsolvable> {
+    // however long it is, an update that's already in order doesn't need searching
+    if keeps(constraints, pages) {
+        return Ok(Solution { order: pages.to_vec(), moved: Vec::new() });
+    }
+    if pages.len() > MAX_PAGES {
+        return Err(Unsolvable::TooLong);
+    }
+
+    let floor = guess(constraints, pages).map_or(0, |order| in_place(pages, &order));
+    let mut search = Search::new(constraints, pages);
+    let mut state = State { placed: 0, kept: None, deadlines: Vec::new() };
+    let best = search.best(&state, floor);
+    if search.gave_up {
+        return Err(Unsolvable::TooLong);
+    }
+    let mut left = best.ok_or(Unsolvable::Infeasible)?;
+
+    // follow the choices that keep the most pages where they were
+    let mut order = Vec::with_capacity(pages.len());
+    let mut moved = Vec::new();
+    while state.placed != search.full {
+        let (i, keep, next) = search
+            .choices(&state)
+            .into_iter()
+            .find(|(_, keep, next)| {
+                left.checked_sub(*keep as usize).is_some_and(|need| search.best(next, need) == Some(need))
+            })
+            .unwrap();
+
+        order.push(pages[i]);
+        if !keep {
+            moved.push(i);
+        }
+        left -= keep as usize;
+        state = next;
+    }
+
+    moved.sort_unstable();
+    Ok(Solution { order, moved: moved.iter().map(|&i| pages[i]).collect() })
+}
+
+// an order that might keep every constraint: a topological sort of the `Before` ones, with the
+// pages other constraints name moved to the front, the back, or next to each other. None if
+// that breaks something
+fn guess(constraints: &[Constraint], pages: &[i32]) -> Option<Vec<i32>> {
+    let mut after: HashMap<i32, Vec<i32>> = HashMap::new();
+    for constraint in constraints {
+        if let Constraint::Before(a, b) = *constraint {
+            after.entry(a).or_default().push(b);
+        }
+    }
+
+    let mut order = sort_pages(&after, pages).ok()?;
+    let at = |order: &[i32], page| order.iter().position(|&p| p == page);
+    for constraint in constraints {
+        match *constraint {
+            Constraint::First(a) => {
+                if let Some(i) = at(&order, a) {
+                    order.remove(i);
+                    order.insert(0, a);
+                }
+            }
+            Constraint::Last(a) => {
+                if let Some(i) = at(&order, a) {
+                    order.remove(i);
+                    order.push(a);
+                }
+            }
+            // a page paired with itself is always next to itself
+            Constraint::Adjacent(a, b) | Constraint::Within(_, a, b) if a != b => {
+                if let (Some(i), Some(j)) = (at(&order, a), at(&order, b)) {
+                    let (earlier, later) = if i < j { (i, j) } else { (j, i) };
+                    let page = order.remove(later);
+                    order.insert(earlier + 1, page);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    keeps(constraints, &order).then_some(order)
+}
+
+// whether `order` keeps every constraint
+fn keeps(constraints: &[Constraint], order: &[i32]) -> bool {
+    let index: HashMap<i32, usize> = order.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+    constraints.iter().all(|c| c.holds(&index, order.len()))
+}
+
+// how many pages `order` leaves where they were in `pages`: the longest run of them it has in
+// the same order
+fn in_place(pages: &[i32], order: &[i32]) -> usize {
+    let index: HashMap<i32, usize> = pages.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+
+    // the smallest index each length of run so far can end on
+    let mut ends: Vec<usize> = Vec::new();
+    for page in order {
+        let i = index[page];
+        let length = ends.partition_point(|&end| end < i);
+        if length == ends.len() {
+            ends.push(i);
+        } else {
+            ends[length] = i;
+        }
+    }
+    ends.len()
+}
+
+// the constraints on one update, with pages by their position in it
+struct Search {
+    len: usize,
+    full: u64,
+    // the pages each page has to come after
+    after: Vec<u64>,
+    first: Vec<usize>,
+    last: Vec<usize>,
+    // pairs of pages, and the most pages there can be between them
+    gaps: Vec<(usize, usize, usize)>,
+    memo: HashMap<State, Known>,
+    // set once the memo is full, after which nothing it finds can be trusted
+    gave_up: bool,
+}
+
+// what the search has found out about a state: the most pages that can still be left where
+// they were, or that it's fewer than some number (0 if there's no way on at all)
+#[derive(Debug, Clone, Copy)]
+enum Known {
+    Exact(usize),
+    Below(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    placed: u64,
+    // the last page left where it was. the pages left where they were have to stay in the order
+    // the update had them, so any more have to come after it in the update too
+    kept: Option<usize>,
+    // each gap with only one of its pages placed so far, and the last position the other can go
+    deadlines: Vec<(usize, usize)>,
+}
+
+impl Search {
+    fn new(constraints: &[Constraint], pages: &[i32]) -> Self {
+        let index: HashMap<i32, usize> = pages.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+        let at = |page| index.get(&page).copied();
+
+        let mut search = Self {
+            len: pages.len(),
+            full: if pages.len() == 64 { u64::MAX } else { (1 << pages.len()) - 1 },
+            after: vec![0; pages.len()],
+            first: Vec::new(),
+            last: Vec::new(),
+            gaps: Vec::new(),
+            memo: HashMap::new(),
+            gave_up: false,
+        };
+
+        for constraint in constraints {
+            match *constraint {
+                Constraint::Before(a, b) => {
+                    if let (Some(a), Some(b)) = (at(a), at(b)) {
+                        search.after[b] |= 1 << a;
+                    }
+                }
+                Constraint::First(a) => search.first.extend(at(a)),
+                Constraint::Last(a) => search.last.extend(at(a)),
+                Constraint::Adjacent(a, b) | Constraint::Within(_, a, b) => {
+                    let most = if let Constraint::Within(most, ..) = *constraint { most } else { 0 };
+                    if let (Some(a), Some(b)) = (at(a), at(b)) {
+                        if a != b {
+                            search.gaps.push((a, b, most));
+                        }
+                    }
+                }
+            }
+        }
+
+        search
+    }
+
+    // the state after placing page `i` next, or None if that breaks a constraint
+    fn place(&self, state: &State, i: usize) -> Option<State> {
+        let position = state.placed.count_ones() as usize;
+
+        if state.placed & (1 << i) != 0 || self.after[i] & !state.placed != 0 {
+            return None;
+        }
+        if self.first.iter().any(|&first| (first == i) != (position == 0)) {
+            return None;
+        }
+        if self.last.iter().any(|&last| last == i && position + 1 != self.len) {
+            return None;
+        }
+
+        let mut deadlines = Vec::with_capacity(state.deadlines.len() + 1);
+        for &(gap, deadline) in &state.deadlines {
+            let (a, b, _) = self.gaps[gap];
+            if a == i || b == i {
+                if position > deadline {
+                    return None;
+                }
+            } else if deadline <= position {
+                // there's nowhere left for the other page
+                return None;
+            } else {
+                deadlines.push((gap, deadline));
+            }
+        }
+
+        for (gap, &(a, b, most)) in self.gaps.iter().enumerate() {
+            let other = if a == i { b } else if b == i { a } else { continue };
+            if state.placed & (1 << other) == 0 {
+                deadlines.push((gap, position + most + 1));
+            }
+        }
+        deadlines.sort_unstable();
+
+        Some(State { placed: state.placed | 1 << i, kept: state.kept, deadlines })
+    }
+
+    // every way on from `state`: which page goes next, whether it's left where it was, and the
+    // state after
+    fn choices(&self, state: &State) -> Vec<(usize, bool, State)> {
+        let mut choices = Vec::new();
+        for i in 0..self.len {
+            let Some(next) = self.place(state, i) else { continue };
+
+            if state.kept.is_none_or(|kept| i > kept) {
+                choices.push((i, true, State { kept: Some(i), ..next.clone() }));
+            }
+            choices.push((i, false, next));
+        }
+        choices
+    }
+
+    // at most how many more pages can be left where they were: the ones still to place that come
+    // after the last one kept
+    fn most(&self, state: &State) -> usize {
+        let later = match state.kept {
+            Some(kept) => self.full & !(u64::MAX >> (63 - kept)),
+            None => self.full,
+        };
+        (later & !state.placed).count_ones() as usize
+    }
+
+    // the most pages that can still be left where they were from `state`, as long as that's at
+    // least `need`. None means it's fewer, or there's no way to place the rest at all; either way
+    // the search doesn't go down paths that can't keep `need` pages
+    fn best(&mut self, state: &State, need: usize) -> Option<usize> {
+        if state.placed == self.full {
+            return (need == 0).then_some(0);
+        }
+        if self.gave_up || self.most(state) < need {
+            return None;
+        }
+        match self.memo.get(state) {
+            Some(&Known::Exact(kept)) => return (kept >= need).then_some(kept),
+            Some(&Known::Below(below)) if need >= below => return None,
+            _ => (),
+        }
+        if self.memo.len() >= MAX_STATES {
+            self.gave_up = true;
+            return None;
+        }
+
+        let mut best: Option<usize> = None;
+        for (_, keep, next) in self.choices(state) {
+            // after the first way on, only a better one is worth finding
+            let want = best.map_or(need, |best| best + 1).saturating_sub(keep as usize);
+            if let Some(kept) = self.best(&next, want) {
+                best = Some(kept + keep as usize);
+            }
+        }
+
+        if !self.gave_up {
+            self.memo.insert(state.clone(), best.map_or(Known::Below(need), Known::Exact));
+        }
+        best
+    }
+}
+
+/// A rule an update breaks: `rule.0` has to come before `rule.1`, but they're at `positions`
+/// (counted from 0) the other way round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: (i32, i32),
+    pub positions: (usize, usize),
+}
+
+fn violations(rules: &Rules, pages: &[i32]) -> Vec<Violation> {
+    let index: HashMap<i32, usize> = pages.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+
+    rules
+        .pairs()
+        .filter_map(|(a, b)| {
+            let positions = (*index.get(&a)?, *index.get(&b)?);
+            (positions.0 > positions.1).then_some(Violation { rule: (a, b), positions })
+        })
+        .collect()
+}
+
+/// Every update that's out of order, numbered from 1, with each rule it breaks (and for the
+/// puzzle's rules where the two pages are, also from 1), and the order that would fix it.
+pub fn report(input: &str) -> Result<String, ParseError> {
+    let (rules, updates) = read_input(input)?;
+    let after = rules.successors();
+
+    let mut out = String::new();
+    let mut broken = 0;
+    for (number, update) in updates.iter().enumerate() {
+        let violations = violations(&rules, &update.pages);
+        let index: HashMap<i32, usize> = update.pages.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+        let others: Vec<&Constraint> = rules
+            .0
+            .iter()
+            .filter(|c| !matches!(c, Constraint::Before(..)) && !c.holds(&index, update.pages.len()))
+            .collect();
+        if violations.is_empty() && others.is_empty() {
+            continue;
+        }
+
+        broken += 1;
+        out += &format!("update {}: {}\n", number + 1, update.line);
+        for v in &violations {
+            out += &format!(
+                "  breaks {}|{}: {} is page {} but {} is page {}\n",
+                v.rule.0,
+                v.rule.1,
+                v.rule.0,
+                v.positions.0 + 1,
+                v.rule.1,
+                v.positions.1 + 1
+            );
+        }
+
+        for constraint in others {
+            out += &format!("  breaks {}\n", constraint);
+        }
+
+        match rules.fix(&after, &update.pages) {
+            Ok(sorted) => {
+                let pages: Vec<String> = sorted.iter().map(i32::to_string).collect();
+                out += &format!("  fixed: {}\n", pages.join(","));
+            }
+            Err(reason) => out += &format!("  can't be fixed: {}\n", reason),
+        }
+    }
+
+    out += &format!("{} of {} updates are out of order\n", broken, updates.len());
+    Ok(out)
+}
+
+/// The rules as a Graphviz digraph, with an edge from each page to the pages it has to come
+/// before. Given an update (numbered from 1), only the rules between its pages are drawn, and
+/// the ones it breaks are red.
+pub fn dot(input: &str, update: Option<usize>) -> Result<String, ParseError> {
+    let (rules, updates) = read_input(input)?;
+
+    let (pages, broken) = match update {
+        Some(number) => {
+            let update = number
+                .checked_sub(1)
+                .and_then(|i| updates.get(i))
+                .ok_or_else(|| ParseError::missing(input, format!("an update numbered {}", number)))?;
+            let broken: Vec<(i32, i32)> = violations(&rules, &update.pages).iter().map(|v| v.rule).collect();
+            (Some(update.pages.clone()), broken)
+        }
+        None => (None, Vec::new()),
+    };
+
+    let mut out = String::from("digraph rules {\n");
+    if let Some(pages) = &pages {
+        // in the order the update has them
+        for page in pages {
+            out += &format!("    \"{}\";\n", page);
+        }
+    }
+
+    for (a, b) in rules.pairs() {
+        if pages.as_ref().is_some_and(|pages| !pages.contains(&a) || !pages.contains(&b)) {
+            continue;
+        }
+
+        let colour = if broken.contains(&(a, b)) { " [color=red]" } else { "" };
+        out += &format!("    \"{}\" -> \"{}\"{};\n", a, b, colour);
+    }
+
+    out += "}\n";
+    Ok(out)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    read_input(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i32, ParseError> {
+    let (rules, updates) = read_input(input)?;
+
+    let mut total = 0;
+    for update in updates {
+        if rules.keeps(&update.pages) {
+            // get middle number
+            total += update.pages[update.pages.len() / 2];
+        }
+    }
+
+    Ok(total)
+}
+
+// an update that can't be put in order is still a valid input, so it's left out of the total
+// with a warning rather than failing the whole part
+pub fn part_2(input: &str) -> Result<i32, ParseError> {
+    let (rules, updates) = read_input(input)?;
+    let after = rules.successors();
+
+    let mut total = 0;
+    for (number, update) in updates.iter().enumerate() {
+        if rules.keeps(&update.pages) {
+            continue;
+        }
+
+        match rules.fix(&after, &update.pages) {
+            Ok(sorted) => {
+                common::diag!(2, "Original: {:?}, Fixed: {:?}", update.pages, sorted);
+                // get middle number
+                total += sorted[sorted.len() / 2];
+            }
+            Err(reason) => {
+                common::diag!(0, "warning: left out update {} ({}), which can't be put in order: {}", number + 1, update.line, reason);
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Constraint, Cycle, Solution, Unsolvable, Violation};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 143);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 123);
+    }
+
+    #[test]
+    fn cycles_are_errors() {
+        let input = "1|2\n2|3\n3|1\n4|1\n\n4,1,2\n2,3,4,1\n";
+        let after = super::read_input(input).unwrap().0.successors();
+
+        assert_eq!(super::sort_pages(&after, &[2, 1, 4]), Ok(vec![4, 1, 2]));
+        assert_eq!(super::sort_pages(&after, &[2, 3, 4, 1]), Err(Cycle(vec![2, 3, 1])));
+        assert_eq!(Cycle(vec![2, 3, 1]).to_string(), "2 -> 3 -> 1 -> 2");
+
+        // the second update can't be fixed, so only the first counts
+        assert_eq!(super::part_1(input).unwrap(), 1);
+        assert_eq!(super::part_2(input).unwrap(), 0);
+    }
+
+    #[test]
+    fn violations_of_the_example() {
+        let (rules, updates) = super::read_input(EXAMPLE).unwrap();
+
+        assert!(super::violations(&rules, &updates[0].pages).is_empty());
+        assert_eq!(super::violations(&rules, &updates[3].pages), [Violation { rule: (97, 75), positions: (1, 0) }]);
+
+        let report = super::report(EXAMPLE).unwrap();
+        assert!(report.contains("update 4: 75,97,47,61,53\n  breaks 97|75: 97 is page 2 but 75 is page 1\n  fixed: 97,75,47,61,53\n"));
+        assert!(report.ends_with("3 of 6 updates are out of order\n"));
+    }
+
+    #[test]
+    fn dot_of_one_update() {
+        let dot = super::dot(EXAMPLE, Some(6)).unwrap();
+
+        assert!(dot.starts_with("digraph rules {\n    \"97\";\n    \"13\";\n"));
+        assert!(dot.contains("    \"97\" -> \"13\";\n"));
+        assert!(dot.contains("    \"29\" -> \"13\" [color=red];\n"));
+        assert!(!dot.contains("\"53\""));
+        assert_eq!(super::dot(EXAMPLE, None).unwrap().matches("->").count(), 21);
+        assert!(super::dot(EXAMPLE, Some(7)).is_err());
+    }
+
+    #[test]
+    fn extended_rules() {
+        let input = "1|2\nfirst 3\nlast 4\nadjacent 5,6\nwithin 2 1,4\n\n4,2,5,1,6,3\n";
+        let (rules, updates) = super::read_input(input).unwrap();
+
+        let written: Vec<String> = rules.0.iter().map(|c| c.to_string()).collect();
+        assert_eq!(written.join("\n"), input.split("\n\n").next().unwrap());
+
+        let solution = super::solve(&rules.0, &updates[0].pages).unwrap();
+        assert_eq!(solution, Solution { order: vec![3, 5, 6, 1, 2, 4], moved: vec![4, 2, 1, 3] });
+        assert_eq!(super::part_2(input).unwrap(), 1);
+
+        assert_eq!(super::solve(&[Constraint::First(1), Constraint::First(2)], &[1, 2]), Err(Unsolvable::Infeasible));
+        // a page paired with itself, last in the update
+        assert_eq!(super::part_2("adjacent 5,5\n\n4,6,8,1,2,7,3,5\n").unwrap(), 0);
+        assert!(super::read_input("within 1,2\n\n1,2\n").is_err());
+        assert!(super::read_input("adjacent 1|2\n\n1,2\n").is_err());
+    }
+
+    #[test]
+    fn solve_long_updates() {
+        use Constraint::*;
+
+        // only page 2 needs to move, though nothing pins down the order of the others
+        let pages: Vec<i32> = [1].into_iter().chain(3..=20).chain([2]).collect();
+        let solution = super::solve(&[Adjacent(1, 2)], &pages).unwrap();
+        assert!(super::keeps(&[Adjacent(1, 2)], &solution.order));
+        assert_eq!(solution.moved.len(), 1);
+        assert_eq!(&solution.order[..2], [1, 2]);
+
+        let input = format!("adjacent 1,2\n3|4\n\n{}\n", pages.iter().map(i32::to_string).collect::<Vec<_>>().join(","));
+        assert_eq!(super::part_2(&input).unwrap(), 11);
+
+        // longer than the search goes, but already in order
+        let pages: Vec<i32> = (1..=25).collect();
+        let constraints = [First(1), Within(3, 4, 8), Before(20, 21)];
+        let solution = super::solve(&constraints, &pages).unwrap();
+        assert!(super::keeps(&constraints, &solution.order));
+        assert_eq!(solution, Solution { order: pages.clone(), moved: vec![] });
+
+        let input = format!("first 1\n\n{}\n", pages.iter().map(i32::to_string).collect::<Vec<_>>().join(","));
+        assert_eq!(super::part_1(&input).unwrap(), 13);
+        assert_eq!(super::part_2(&input).unwrap(), 0);
+    }
+
+    // every order of the pages
+    fn permutations(pages: &[i32]) -> Vec<Vec<i32>> {
+        if pages.is_empty() {
+            return vec![Vec::new()];
+        }
+
+        (0..pages.len())
+            .flat_map(|i| {
+                let mut rest = pages.to_vec();
+                let page = rest.remove(i);
+                permutations(&rest).into_iter().map(move |mut order| {
+                    order.insert(0, page);
+                    order
+                })
+            })
+            .collect()
+    }
+
+    // the longest run of pages two orders have in the same order
+    fn common(a: &[i32], b: &[i32]) -> usize {
+        let mut longest = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                longest[i + 1][j + 1] = if a[i] == b[j] { longest[i][j] + 1 } else { longest[i][j + 1].max(longest[i + 1][j]) };
+            }
+        }
+        longest[a.len()][b.len()]
+    }
+
+    #[test]
+    fn solve_matches_trying_every_order() {
+        use Constraint::*;
+
+        let pages = [5, 3, 8, 1, 9, 2];
+        let sets = [
+            vec![Before(1, 5), Before(2, 3)],
+            vec![First(9), Last(5), Adjacent(3, 2)],
+            vec![Within(1, 5, 2), Within(0, 8, 9), Before(9, 1)],
+            vec![Adjacent(5, 3), Adjacent(3, 8), Adjacent(8, 5)],
+            vec![Last(2), Within(2, 2, 5), Before(3, 8), First(1)],
+        ];
+
+        for constraints in &sets {
+            let best = permutations(&pages)
+                .into_iter()
+                .filter(|order| {
+                    let index: HashMap<i32, usize> = order.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+                    constraints.iter().all(|c| c.holds(&index, order.len()))
+                })
+                .map(|order| pages.len() - common(&pages, &order))
+                .min();
+
+            let solution = super::solve(constraints, &pages);
+            assert_eq!(solution.as_ref().ok().map(|s| s.moved.len()), best, "{:?}", constraints);
+
+            if let Ok(solution) = solution {
+                let index: HashMap<i32, usize> = solution.order.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+                assert!(constraints.iter().all(|c| c.holds(&index, pages.len())));
+                assert_eq!(pages.len() - common(&pages, &solution.order), solution.moved.len());
+            }
+        }
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,416 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use common::ParseError;
+use grid::{Dir, Grid, Point};
+use rayon::prelude::*;
+use recorder::Recorder;
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+struct Guard {
+    pos: Point,
+    facing: Dir,
+}
+
+impl Guard {
+    fn symbol(&self) -> char {
+        match self.facing {
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Open,
+    // anything the guard can't walk through, kept as it was drawn
+    Obstacle(char),
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    grid: Grid<Cell>,
+}
+
+// the map and every guard on it, in reading order
+fn generate_map(input: &str) -> Result<(Map, Vec<Guard>), ParseError> {
+    let mut guards = Vec::new();
+
+    let grid = Grid::parse(input, |p, c| {
+        let facing = match c {
+            '^' => Dir::Up,
+            '>' => Dir::Right,
+            'v' => Dir::Down,
+            '<' => Dir::Left,
+            '.' => return Some(Cell::Open),
+            // the puzzle only uses `#`, but any other mark is just as solid
+            c if c.is_ascii_graphic() => return Some(Cell::Obstacle(c)),
+            _ => return None,
+        };
+        guards.push(Guard { pos: p, facing });
+        Some(Cell::Open)
+    }, "`.`, a guard (`^`, `>`, `v` or `<`) or an obstacle such as `#`")?;
+
+    if guards.is_empty() {
+        return Err(ParseError::missing(input, "a guard (`^`, `>`, `v` or `<`) somewhere on the map"));
+    }
+
+    Ok((Map { grid }, guards))
+}
+
+fn get_next_move(map: &Map, guard: &mut Guard, previous_positions: &mut HashSet<Guard>) -> (Option<Point>, bool, bool) { // moved to, still on the map, loop
+    // check position in front of guard
+    let front = guard.pos + guard.facing.delta();
+
+    let Some(&cell) = map.grid.get(front) else {
+        return (None, false, false);
+    };
+
+    if previous_positions.contains(guard) {
+        return (None, false, true);
+    }
+
+    previous_positions.insert(*guard);
+
+    // check if position in front of guard is a wall
+    if let Cell::Obstacle(_) = cell {
+        guard.facing = guard.facing.turn_right();
+        (None, true, false)
+    } else {
+        // move forward
+        guard.pos = front;
+        (Some(front), true, false)
+    }
+}
+
+// for every cell and facing, the cell the guard stops on in front of the next obstacle, or None
+// if it walks off the map first
+struct Jumps(Grid<[Option<Point>; 4]>);
+
+impl Jumps {
+    fn new(map: &Map) -> Self {
+        let mut jumps = map.grid.map(|_| [None; 4]);
+        let points: Vec<Point> = map.grid.points().collect();
+
+        for facing in Dir::ALL {
+            // the cell in front has to be worked out first, which is the cell before in reading
+            // order when facing up or left, and after it when facing right or down
+            let order: Box<dyn Iterator<Item = &Point>> = match facing {
+                Dir::Up | Dir::Left => Box::new(points.iter()),
+                Dir::Right | Dir::Down => Box::new(points.iter().rev()),
+            };
+
+            for &p in order {
+                let front = p + facing.delta();
+                jumps[p][facing as usize] = match map.grid.get(front) {
+                    None => None,
+                    Some(Cell::Obstacle(_)) => Some(p),
+                    Some(Cell::Open) => jumps[front][facing as usize],
+                };
+            }
+        }
+
+        Self(jumps)
+    }
+
+    // where the guard stops, with `obstacle` added to the map
+    fn stop(&self, guard: &Guard, obstacle: Point) -> Option<Point> {
+        let delta = guard.facing.delta();
+        let stop = self.0[guard.pos][guard.facing as usize];
+
+        // how far straight ahead the obstacle is, if it's in front at all
+        let offset = obstacle - guard.pos;
+        let ahead = if delta.x == 0 {
+            (offset.x == 0).then_some(offset.y * delta.y)
+        } else {
+            (offset.y == 0).then_some(offset.x * delta.x)
+        };
+
+        match ahead {
+            Some(distance) if distance > 0 && stop.is_none_or(|stop| distance as usize <= stop.manhattan(guard.pos)) => {
+                Some(obstacle - delta)
+            }
+            _ => stop,
+        }
+    }
+}
+
+// whether an obstacle at `obstacle` sends the guard round in a loop. the guard goes from one
+// obstacle to the next, and it's in a loop once it turns at the same place the same way twice
+fn loops_with(jumps: &Jumps, mut guard: Guard, obstacle: Point) -> bool {
+    let mut turns = HashSet::new();
+
+    while let Some(stop) = jumps.stop(&guard, obstacle) {
+        guard.pos = stop;
+        if !turns.insert(guard) {
+            return true;
+        }
+        guard.facing = guard.facing.turn_right();
+    }
+
+    false
+}
+
+// walks every guard at the same time, one step or turn each per round, until they've all left the
+// map. they walk straight through each other. gives where each guard was and which way it faced
+// after every step or turn, starting from where it started
+fn patrol(map: &Map, guards: &[Guard], mut recorder: Option<&mut Recorder>) -> Vec<Vec<Guard>> {
+    let mut walks: Vec<(Vec<Guard>, HashSet<Guard>, bool)> =
+        guards.iter().map(|&guard| (vec![guard], HashSet::new(), true)).collect();
+    // only for drawing the frames
+    let mut visited = map.grid.map(|_| false);
+
+    while walks.iter().any(|&(_, _, walking)| walking) {
+        if let Some(recorder) = recorder.as_deref_mut() {
+            let at: HashSet<Point> = walks
+                .iter()
+                .filter(|&&(_, _, walking)| walking)
+                .map(|(states, _, _)| states[states.len() - 1].pos)
+                .collect();
+            recorder.push(&map.grid, |p, cell| {
+                if at.contains(&p) {
+                    recorder::RED
+                } else if let Cell::Obstacle(_) = cell {
+                    recorder::GREY
+                } else if visited[p] {
+                    recorder::BLUE
+                } else {
+                    recorder::BLACK
+                }
+            });
+        }
+
+        for (states, seen, walking) in walks.iter_mut().filter(|(_, _, walking)| *walking) {
+            let mut guard = states[states.len() - 1];
+            let (pos, on_map, _) = get_next_move(map, &mut guard, seen);
+
+            if let Some(pos) = pos {
+                visited[pos] = true;
+            }
+
+            if on_map {
+                states.push(guard);
+            } else {
+                *walking = false;
+            }
+        }
+    }
+
+    walks.into_iter().map(|(states, _, _)| states).collect()
+}
+
+// every position a guard steps onto, in order, from its states on patrol
+fn steps(states: &[Guard]) -> impl Iterator<Item = Point> + '_ {
+    states.windows(2).filter(|pair| pair[0].pos != pair[1].pos).map(|pair| pair[1].pos)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    generate_map(input).map(|_| ())
+}
+
+/// What the guards' patrols look like: how many cells they visit between them, counting where
+/// they start, and every cell an obstruction could go in to send any of them round in a loop, in
+/// reading order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Obstructions {
+    pub visited: usize,
+    pub loops: Vec<Point>,
+}
+
+pub fn obstructions(input: &str) -> Result<Obstructions, ParseError> {
+    let (map, guards) = generate_map(input)?;
+
+    // an obstruction anywhere the guards don't walk can't change anything, and they pass some
+    // cells more than once but each only needs trying once
+    let mut seen: HashSet<Point> = guards.iter().map(|guard| guard.pos).collect();
+    let candidates: Vec<Point> = patrol(&map, &guards, None)
+        .iter()
+        .flat_map(|states| steps(states))
+        .filter(|&pos| seen.insert(pos))
+        .collect();
+
+    let jumps = Jumps::new(&map);
+    let mut loops: Vec<Point> = candidates
+        .par_iter()
+        .copied()
+        .filter(|&pos| guards.iter().any(|&guard| loops_with(&jumps, guard, pos)))
+        .collect();
+    loops.sort_by_key(|p| (p.y, p.x));
+
+    Ok(Obstructions { visited: seen.len(), loops })
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    let (map, guards) = generate_map(input)?;
+
+    let mut visited: HashSet<Point> = guards.iter().map(|guard| guard.pos).collect();
+    visited.extend(patrol(&map, &guards, None).iter().flat_map(|states| steps(states)));
+
+    Ok(visited.len())
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    Ok(obstructions(input)?.loops.len())
+}
+
+
+// records the guards' patrol, one frame per step, which is the same for both parts
+pub fn record(input: &str, _part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+    let (map, guards) = generate_map(input)?;
+    patrol(&map, &guards, Some(recorder));
+    Ok(())
+}
+
+/// The map with the guards' patrols drawn over it: `|` where they only walk up and down, `-`
+/// where they only walk across, and `+` where they turn or cross their own or each other's path.
+/// The guards are shown where they start.
+pub fn render(input: &str) -> Result<String, ParseError> {
+    let (map, guards) = generate_map(input)?;
+
+    // 1 for walking up or down through a cell, 2 for across, 3 for both
+    let mut trails = map.grid.map(|_| 0u8);
+    for states in patrol(&map, &guards, None) {
+        for pair in states.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if from.pos == to.pos {
+                trails[from.pos] = 3;
+            } else {
+                let axis = match from.facing {
+                    Dir::Up | Dir::Down => 1,
+                    Dir::Left | Dir::Right => 2,
+                };
+                trails[from.pos] |= axis;
+                trails[to.pos] |= axis;
+            }
+        }
+    }
+
+    Ok(map.grid.render(|p, &cell| {
+        if let Some(guard) = guards.iter().find(|guard| guard.pos == p) {
+            return guard.symbol();
+        }
+
+        match (cell, trails[p]) {
+            (Cell::Obstacle(c), _) => c,
+            (Cell::Open, 1) => '|',
+            (Cell::Open, 2) => '-',
+            (Cell::Open, 3) => '+',
+            (Cell::Open, _) => '.',
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use grid::Point;
+
+    use super::{Cell, Guard, Map};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 41);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 6);
+    }
+
+    #[test]
+    fn obstructions_of_the_example() {
+        let found = super::obstructions(EXAMPLE).unwrap();
+
+        assert_eq!(found.visited, 41);
+        assert_eq!(
+            found.loops,
+            [
+                Point::new(3, 6),
+                Point::new(6, 7),
+                Point::new(7, 7),
+                Point::new(1, 8),
+                Point::new(3, 8),
+                Point::new(7, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn guards_facing_every_way() {
+        let (map, guards) = super::generate_map("^.O\n.>v\n<.#\n").unwrap();
+
+        assert_eq!(
+            guards.iter().map(|guard| (guard.pos, guard.symbol())).collect::<Vec<_>>(),
+            [(Point::new(0, 0), '^'), (Point::new(1, 1), '>'), (Point::new(2, 1), 'v'), (Point::new(0, 2), '<')]
+        );
+        assert_eq!(map.grid[Point::new(2, 0)], Cell::Obstacle('O'));
+
+        assert!(super::parse("..\n.^\t\n").unwrap_err().to_string().contains("a guard (`^`, `>`, `v` or `<`)"));
+        assert!(super::parse("..\n#.\n").is_err());
+    }
+
+    #[test]
+    fn render_two_guards() {
+        let input = ".#..\n...#\n.^..\n...<\n";
+
+        assert_eq!(super::part_1(input).unwrap(), 8);
+        assert_eq!(super::render(input).unwrap(), ".#..\n.++#\n.^|.\n--+<\n");
+    }
+
+    // the guard walked one cell at a time with the obstacle put in the map, as it used to be
+    fn loops_step_by_step(map: &Map, start: Guard, obstacle: Point) -> bool {
+        if map.grid[obstacle] != Cell::Open || obstacle == start.pos {
+            return false;
+        }
+
+        let mut map = map.clone();
+        map.grid[obstacle] = Cell::Obstacle('O');
+        let mut guard = start;
+        let mut seen = HashSet::new();
+
+        loop {
+            let (_, moved, in_loop) = super::get_next_move(&map, &mut guard, &mut seen);
+            if in_loop || !moved {
+                return in_loop;
+            }
+        }
+    }
+
+    #[test]
+    fn jumps_match_walking() {
+        // every free cell on the example, and every seventh on the real input
+        for (input, step) in [(EXAMPLE, 1), (INPUT, 7)] {
+            let (map, guards) = super::generate_map(input).unwrap();
+            let guard = guards[0];
+            let jumps = super::Jumps::new(&map);
+
+            for obstacle in map.grid.points().filter(|&p| map.grid[p] == Cell::Open && p != guard.pos).step_by(step) {
+                assert_eq!(
+                    super::loops_with(&jumps, guard, obstacle),
+                    loops_step_by_step(&map, guard, obstacle),
+                    "obstacle at {:?}",
+                    obstacle
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,131 @@
+#![allow(dead_code)]
+
+use common::ParseError;
+
+fn parse_equation(input: &str, str: &str) -> Result<(f64, Vec<f64>), ParseError> {
+    // split the string into two parts, split by ":" character
+
+    let (rule, numbers) = str
+        .split_once(":")
+        .ok_or_else(|| ParseError::after(input, str, "`:` after the test value"))?;
+
+    // second part is the list of numbers, split by " "
+    let numbers: Vec<f64> = numbers
+        .split(" ")
+        .filter(|x| !x.is_empty())
+        .map(|x| common::parse::<u64>(input, x, "a number").map(|x| x as f64))
+        .collect::<Result<_, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::after(input, str, "at least one number"));
+    }
+
+    // first part is the rule
+    let rule = common::parse::<u64>(input, rule, "a test value")? as f64;
+
+    // return the tuple
+    Ok((rule, numbers))
+}
+
+#[derive(Clone, Debug)]
+enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    fn apply(&self, a: f64, b: f64) -> f64 {
+        match self {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+            Operator::Concat => {
+                let mut temp = a.to_string();
+                temp.push_str(b.to_string().as_str());
+                temp.parse::<f64>().unwrap()
+            }
+        }
+    }
+}
+
+fn valid_eq(eq: &(f64, Vec<f64>), operators: &[Operator]) -> bool {
+    // get the rule
+    let result = eq.0;
+
+    if eq.1.len() == 1 {
+        return eq.1[0] == result;
+    }
+
+    let mut new_eq = eq.clone();
+    // remove the first number
+    new_eq.1.remove(0);
+
+    for operator in operators {
+        new_eq.1[0] = operator.apply(eq.1[0], eq.1[1]);
+
+        if valid_eq(&new_eq, operators) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn parse_equations(input: &str) -> Result<Vec<(f64, Vec<f64>)>, ParseError> {
+    // split the input by new line
+    let lines: Vec<&str> = input.trim().split("\n").collect();
+
+    // parse each line
+    lines.iter().map(|x| parse_equation(input, x)).collect()
+}
+
+fn calibration_result(input: &str, operators: &[Operator]) -> Result<f64, ParseError> {
+    let equations = parse_equations(input)?;
+
+    // find valid equations
+    let valid_eqs: Vec<(f64, Vec<f64>)> = equations.iter().filter(|x| valid_eq(x, operators)).cloned().collect();
+
+    // add together the first number of each valid equation
+    let sum: f64 = valid_eqs.iter().map(|x| x.0).sum();
+
+    Ok(sum)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_equations(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<f64, ParseError> {
+    calibration_result(input, &[Operator::Add, Operator::Multiply])
+}
+
+pub fn part_2(input: &str) -> Result<f64, ParseError> {
+    calibration_result(input, &[Operator::Add, Operator::Multiply, Operator::Concat])
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 3749.0);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 11387.0);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use common::ParseError;
+use grid::{Grid, Point};
+
+#[derive(Debug, Clone, Copy)]
+enum Item {
+    Empty,
+    Antenna(char),
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    map: Grid<Item>,
+    antennas: Vec<(Point, char)>,
+}
+
+fn find_antinodes(p1: Point, p2: Point, map: &Grid<Item>, harmonics: bool) -> Vec<Point> {
+    // the antinodes are like if you drew a line between the two points, found the distance, then went that same distance in the opposite direction (both sides)
+    let step = p2 - p1;
+    
+    let mut out = Vec::new();
+
+    if !harmonics {
+        out.push(p1 - step);
+        out.push(p2 + step);
+        return out;
+    }
+
+    let mut pos_1 = p1;
+
+    out.push(p1);
+    out.push(p2);
+    
+    while map.contains(pos_1) {
+        pos_1 -= step;
+        out.push(pos_1);
+    }
+
+    let mut pos_2 = p2;
+
+    while map.contains(pos_2) {
+        pos_2 += step;
+        out.push(pos_2);
+    }
+
+    out
+}
+
+fn create_map(input: &str) -> Result<Map, ParseError> {
+    let mut antennas = Vec::new();
+
+    // antennas are marked with a letter or digit
+    let map = Grid::parse(input, |p, c| match c {
+        '.' => Some(Item::Empty),
+        _ if c.is_ascii_alphanumeric() => {
+            antennas.push((p, c));
+            Some(Item::Antenna(c))
+        }
+        _ => None,
+    }, "`.` or an antenna frequency")?;
+
+    Ok(Map { map, antennas })
+}
+
+fn count_antinodes(input: &str, harmonics: bool) -> Result<usize, ParseError> {
+    // create map
+    let map = create_map(input)?;
+
+    // find antinodes
+    let mut antinodes = HashSet::new();
+
+    for i in 0..map.antennas.len() {
+        for j in i+1..map.antennas.len() {
+            let (p1, a1) = map.antennas[i];
+            let (p2, a2) = map.antennas[j];
+
+            if a1 != a2 { continue; }
+            if p1 == p2 { continue; }
+
+            let points = find_antinodes(p1, p2, &map.map, harmonics);
+
+            for point in points {
+                if map.map.contains(point) {
+                    antinodes.insert(point);
+                }
+            }
+        }
+    }
+
+    //display_map(&map, &antinodes);
+
+    Ok(antinodes.len())
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    create_map(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<usize, ParseError> {
+    count_antinodes(input, false)
+}
+
+pub fn part_2(input: &str) -> Result<usize, ParseError> {
+    count_antinodes(input, true)
+}
+
+
+fn display_map(map: &Map, antinodes: &HashSet<Point>) {
+    eprint!("{}", map.map.render(|p, item| {
+        if antinodes.contains(&p) {
+            '#'
+        } else {
+            match item {
+                Item::Empty => '.',
+                Item::Antenna(c) => *c,
+            }
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 14);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 34);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,313 @@
+#![allow(dead_code)]
+
+use common::ParseError;
+use grid::{Grid, Point};
+use recorder::{Recorder, Rgb};
+
+fn parse_to_ints(input: &str) -> Result<Vec<i8>, ParseError> {
+    // every char is a number
+    input
+        .trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as i8)
+                .ok_or_else(|| ParseError::at(input, &input[i..i + c.len_utf8()], "a digit"))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Block {
+    Free(i8), // size
+    Used(i8, i64, bool), // size id
+}
+
+impl Block {
+    fn is_free(&self) -> bool {
+        matches!(self, Block::Free(_))
+    }
+
+    fn has_been_moved(&self) -> bool {
+        match self {
+            Block::Free(_) => false,
+            Block::Used(_, _, moved) => *moved,
+        }
+    }
+
+    fn get_size(&self) -> i8 {
+        match self {
+            Block::Free(size) => *size,
+            Block::Used(size, _, _) => *size,
+        }
+    }
+}
+
+fn split_to_blocks(numbers: &[i8]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut free = false;
+    for (i, item) in numbers.iter().enumerate() {
+        if free {
+            blocks.push(Block::Free(*item));
+        } else {
+            blocks.push(Block::Used(*item, (i as i64) / 2, false));
+        }
+
+        free = !free;
+    }
+    blocks
+}
+
+fn run_step(blocks: &mut Vec<Block>) -> bool {
+    let mut longest_free;
+    let mut open_space;
+    let mut last_used;
+    loop {
+        longest_free = (None, 0);
+        for (i, block) in blocks.iter().enumerate() {
+            if let Block::Free(size) = block {
+                if *size > longest_free.1 {
+                    longest_free = (Some(i), *size);
+                }
+            }
+        }
+
+        if longest_free.0.is_none() {
+            return false;
+        }
+
+        let longest_free = (longest_free.0.unwrap(), longest_free.1);
+        
+        let mut used_vec = Vec::new();
+        for (i, block) in blocks.iter().enumerate() {
+            if block.is_free() {
+                continue;
+            }
+
+            if block.has_been_moved() {
+                continue;
+            }
+
+            let (size, id, _) = match block {
+                Block::Used(size, id, _) => (*size, *id, i),
+                _ => unreachable!(),
+            };
+
+            if size > longest_free.1 {
+                continue;
+            }
+
+            used_vec.push((i, size, id));
+        }
+
+        if used_vec.is_empty() {
+            return false;
+        }
+
+        used_vec.sort_by_key(|a| a.2);
+
+        last_used = used_vec.pop().unwrap();
+
+        open_space = None;
+        for (i, block) in blocks.iter().enumerate() {
+            if i > last_used.0 {
+                break;
+            }
+
+            if block.is_free() {
+                let size = match block {
+                    Block::Free(size) => *size,
+                    _ => unreachable!(),
+                };
+
+                if size >= last_used.1 {
+                    open_space = Some((i, size));
+                    break;
+                }
+            }
+        }
+
+        if open_space.is_some() {
+            break;
+        } else {
+            // mark last_used as moved
+            blocks[last_used.0] = Block::Used(last_used.1, last_used.2, true);
+        }
+    }
+
+    let open_space = open_space.unwrap();
+
+    if open_space.1 == last_used.1 {
+        blocks.swap(last_used.0, open_space.0);
+    } else {
+        blocks[last_used.0] = Block::Free(last_used.1);
+        blocks[open_space.0] = Block::Used(last_used.1, last_used.2, true);
+
+        // make a new free block with the remaining space
+        let new_size = open_space.1 - last_used.1;
+        blocks.insert(open_space.0+1, Block::Free(new_size));
+    }
+
+    true
+}
+
+// the disk one block at a time, None is free space
+fn lay_out(blocks: &[Block]) -> Vec<Option<i64>> {
+    let mut disk = Vec::new();
+    for block in blocks {
+        let id = match block {
+            Block::Free(_) => None,
+            Block::Used(_, id, _) => Some(*id),
+        };
+        disk.extend(std::iter::repeat_n(id, block.get_size() as usize));
+    }
+    disk
+}
+
+// the disk wrapped into a square, for recording
+fn disk_square(disk: &[Option<i64>]) -> Grid<Option<i64>> {
+    let width = (disk.len() as f64).sqrt().ceil().max(1.0) as usize;
+    Grid::from_fn(width, disk.len().div_ceil(width), |p| {
+        disk.get(p.y as usize * width + p.x as usize).copied().flatten()
+    })
+}
+
+fn file_colour(_: Point, id: &Option<i64>) -> Rgb {
+    match id {
+        Some(id) => recorder::DISTINCT[*id as usize % recorder::DISTINCT.len()],
+        None => recorder::BLACK,
+    }
+}
+
+fn compact_blocks(blocks: &[Block], mut recorder: Option<&mut Recorder>) -> i64 {
+    let mut disk = lay_out(blocks);
+
+    // move the last used block into the first free one until they meet
+    let mut left = 0;
+    let mut right = disk.len().saturating_sub(1);
+    while left < right {
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.push_with(|| disk_square(&disk), file_colour);
+        }
+
+        if disk[left].is_some() {
+            left += 1;
+        } else if disk[right].is_none() {
+            right -= 1;
+        } else {
+            disk.swap(left, right);
+        }
+    }
+
+    disk.iter()
+        .enumerate()
+        .filter_map(|(i, id)| id.map(|id| i as i64 * id))
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_to_ints(input).map(|_| ())
+}
+
+pub fn part_1(input: &str) -> Result<i64, ParseError> {
+    let numbers = parse_to_ints(input)?;
+
+    Ok(compact_blocks(&split_to_blocks(&numbers), None))
+}
+
+pub fn part_2(input: &str) -> Result<i64, ParseError> {
+    // parse input to a vector of integers
+    let numbers = parse_to_ints(input)?;
+
+    Ok(generate_checksum(&move_files(&numbers, None)))
+}
+
+// moves whole files into the first gap they fit in
+fn move_files(numbers: &[i8], mut recorder: Option<&mut Recorder>) -> Vec<Block> {
+    // split the vector into blocks
+    let mut blocks = split_to_blocks(numbers);
+
+    //display_blocks(&blocks);
+    loop {
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.push_with(|| disk_square(&lay_out(&blocks)), file_colour);
+        }
+        if !run_step(&mut blocks) {
+            break;
+        }
+    }
+
+    blocks
+}
+
+// records the disk being compacted, one frame per block moved in part 1 or per file in part 2
+pub fn record(input: &str, part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+    let numbers = parse_to_ints(input)?;
+    if part == 2 {
+        move_files(&numbers, Some(recorder));
+    } else {
+        compact_blocks(&split_to_blocks(&numbers), Some(recorder));
+    }
+    Ok(())
+}
+
+fn generate_checksum(blocks: &[Block]) -> i64 {
+    let mut checksum = 0;
+    let mut current_idx = 0;
+    for block in blocks.iter() {
+        if let Block::Used(size, id, _) = block {
+            for _ in 0..*size {
+                checksum += (current_idx) * (*id);
+                current_idx += 1;
+            }
+        } else {
+            current_idx += block.get_size() as i64;
+        }
+    }
+    checksum
+}
+
+fn display_blocks(blocks: &Vec<Block>) {
+    for block in blocks {
+        match block {
+            Block::Free(size) => {
+                for _ in 0..*size {
+                    eprint!(".");
+                }
+            }
+            Block::Used(size, id, _) => {
+                for _ in 0..*size {
+                    eprint!("{}", *id);
+                }
+            }
+        }
+    }
+    eprintln!();
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+    const INPUT: &str = include_str!("../input.txt");
+    const ANSWERS: &str = include_str!("../answers.txt");
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(super::part_1(EXAMPLE).unwrap(), 1928);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(super::part_2(EXAMPLE).unwrap(), 2858);
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(super::part_1(INPUT).unwrap().to_string(), common::answer(ANSWERS, 1));
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(super::part_2(INPUT).unwrap().to_string(), common::answer(ANSWERS, 2));
+    }
+}
@@ -0,0 +1,21 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of pairs of location ids.
+pub const SIZE: usize = 1000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let left = (0..size).map(|_| rng.random_range(10000..100000)).collect::<Vec<u32>>();
+
+    let mut output = String::new();
+    for &id in &left {
+        // plenty of ids on the right are copies of ones on the left, so the similarity score isn't 0
+        let right = if rng.random_bool(0.3) {
+            left[rng.random_range(0..left.len())]
+        } else {
+            rng.random_range(10000..100000)
+        };
+        output += &format!("{}   {}\n", id, right);
+    }
+    output
+}
@@ -0,0 +1,26 @@
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The width and height of the map.
+pub const SIZE: usize = 55;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    // hills that slope down one step at a time away from their peaks, so walking straight
+    // towards a peak from anywhere on its slopes is a trail
+    let peaks = (0..(size * size / 60).max(1))
+        .map(|_| Point::new(rng.random_range(0..size) as isize, rng.random_range(0..size) as isize))
+        .collect::<Vec<_>>();
+
+    let heights = Grid::from_fn(size, size, |p| {
+        // with the odd patch of rough ground breaking up some of the trails
+        if rng.random_bool(0.05) {
+            return rng.random_range(0..=9);
+        }
+        let nearest = peaks.iter().map(|&peak| peak.manhattan(p)).min().unwrap();
+        9 - nearest.min(9)
+    });
+
+    heights.to_string()
+}
@@ -0,0 +1,17 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of stones.
+pub const SIZE: usize = 8;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let stones = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(0..8);
+            rng.random_range(0..10_u32.pow(digits)).to_string()
+        })
+        .collect::<Vec<_>>();
+
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,28 @@
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The width and height of the garden.
+pub const SIZE: usize = 140;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    // every plot belongs to the nearest of a scattering of seeds, with a bit of noise in the
+    // distances so the regions have ragged edges
+    let seeds = (0..(size * size / 40).max(1))
+        .map(|_| {
+            let p = Point::new(rng.random_range(0..size) as isize, rng.random_range(0..size) as isize);
+            (p, rng.random_range(b'A'..=b'Z') as char)
+        })
+        .collect::<Vec<_>>();
+
+    let garden = Grid::from_fn(size, size, |p| {
+        seeds
+            .iter()
+            .min_by_key(|(seed, _)| seed.manhattan(p) * 4 + rng.random_range(0..3))
+            .map(|&(_, plant)| plant)
+            .unwrap()
+    });
+
+    garden.to_string()
+}
@@ -0,0 +1,53 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of claw machines.
+pub const SIZE: usize = 320;
+
+// how much further away part 2 says the prizes are
+const OFFSET: i64 = 10_000_000_000_000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut machines = Vec::new();
+    while machines.len() < size {
+        let a = (rng.random_range(10..100), rng.random_range(10..100));
+        let b = (rng.random_range(10..100), rng.random_range(10..100));
+        // the buttons never push the claw the same way, so there's at most one way to win
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+
+        let mut prize = (rng.random_range(1000..20000), rng.random_range(1000..20000));
+        match rng.random_range(0..3) {
+            // a prize that can be won in part 1
+            0 => {
+                let (presses_a, presses_b) = (rng.random_range(0..=100), rng.random_range(0..=100));
+                prize = (presses_a * a.0 + presses_b * b.0, presses_a * a.1 + presses_b * b.1);
+            }
+            // a prize that can be won once it's moved in part 2: the nearest whole number of
+            // presses to the random prize, when neither count comes out negative
+            1 => {
+                let target = (prize.0 + OFFSET, prize.1 + OFFSET);
+                let det = a.0 * b.1 - a.1 * b.0;
+                let presses_a = (target.0 as i128 * b.1 as i128 - target.1 as i128 * b.0 as i128) / det as i128;
+                let presses_b = (target.1 as i128 * a.0 as i128 - target.0 as i128 * a.1 as i128) / det as i128;
+                if presses_a >= 0 && presses_b >= 0 {
+                    let (presses_a, presses_b) = (presses_a as i64, presses_b as i64);
+                    prize = (
+                        presses_a * a.0 + presses_b * b.0 - OFFSET,
+                        presses_a * a.1 + presses_b * b.1 - OFFSET,
+                    );
+                }
+            }
+            // and the rest are whatever the random prize is
+            _ => (),
+        }
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    machines.join("\n")
+}
@@ -0,0 +1,19 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of robots. The room is always the puzzle's 101 by 103.
+pub const SIZE: usize = 500;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size {
+        output += &format!(
+            "p={},{} v={},{}\n",
+            rng.random_range(0..101),
+            rng.random_range(0..103),
+            rng.random_range(-99..=99),
+            rng.random_range(-99..=99),
+        );
+    }
+    output
+}
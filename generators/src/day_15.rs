@@ -0,0 +1,36 @@
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// The width and height of the warehouse. There are 400 moves for every row of it.
+pub const SIZE: usize = 50;
+
+const MOVES_PER_LINE: usize = 1000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // walls all round and at least one cell inside them for the robot
+    let size = size.max(3);
+    let mut warehouse = Grid::from_fn(size, size, |p| {
+        let edge = p.x == 0 || p.y == 0 || p.x as usize == size - 1 || p.y as usize == size - 1;
+        match rng.random_range(0..20) {
+            _ if edge => '#',
+            0 => '#',
+            1..=6 => 'O',
+            _ => '.',
+        }
+    });
+
+    let robot = Point::new(rng.random_range(1..size - 1) as isize, rng.random_range(1..size - 1) as isize);
+    warehouse[robot] = '@';
+
+    let moves = (0..size * 400).map(|_| *['^', 'v', '<', '>'].choose(rng).unwrap()).collect::<Vec<_>>();
+
+    let mut output = warehouse.to_string();
+    for line in moves.chunks(MOVES_PER_LINE) {
+        output.push('\n');
+        output.extend(line);
+    }
+    output.push('\n');
+    output
+}
@@ -0,0 +1,49 @@
+use grid::{Dir, Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The width and height of the maze, rounded up to an odd number.
+pub const SIZE: usize = 141;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut maze = Grid::new(size, size, '#');
+
+    // a maze carved by a random depth first search between the odd cells...
+    let start = Point::new(1, size as isize - 2);
+    maze[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let mut dirs = Dir::ALL;
+        dirs.shuffle(rng);
+        let next = dirs.into_iter().find(|&dir| {
+            let next = cell + dir.delta() * 2;
+            maze.get(next) == Some(&'#') && next.x > 0 && next.y > 0 && next.x < size as isize - 1 && next.y < size as isize - 1
+        });
+
+        match next {
+            Some(dir) => {
+                maze[cell.step(dir)] = '.';
+                maze[cell + dir.delta() * 2] = '.';
+                stack.push(cell + dir.delta() * 2);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // ...with some walls knocked through, so there's more than one way to the end
+    for _ in 0..size * size / 50 {
+        let p = Point::new(rng.random_range(1..size - 1) as isize, rng.random_range(1..size - 1) as isize);
+        let between_rooms = (p.x % 2 == 1) != (p.y % 2 == 1);
+        if between_rooms {
+            maze[p] = '.';
+        }
+    }
+
+    maze[start] = 'S';
+    maze[Point::new(size as isize - 2, 1)] = 'E';
+    maze.to_string()
+}
@@ -0,0 +1,46 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// How many numbers the program outputs for part 1. Part 2 always looks for the 16 numbers
+/// of the program itself.
+pub const SIZE: usize = 11;
+
+// the same shape as the puzzle's programs, which part 2 relies on: mix the low three bits
+// of `a` with some higher ones, output them, then shift `a` down three bits until it's 0
+fn program(x: u8, y: u8) -> [u8; 16] {
+    [2, 4, 1, x, 7, 5, 0, 3, 1, y, 4, 1, 5, 5, 3, 0]
+}
+
+// the first number `program(x, y)` outputs when it starts with `a`
+fn first_output(a: u64, x: u8, y: u8) -> u8 {
+    let b = (a % 8) ^ x as u64;
+    ((b ^ y as u64 ^ (a >> b)) % 8) as u8
+}
+
+// whether some `a` makes the program output itself, which part 2 needs. `a` is built three
+// bits at a time, working back from the last number output
+fn outputs_itself(a: u64, remaining: &[u8], x: u8, y: u8) -> bool {
+    let Some((&last, rest)) = remaining.split_last() else {
+        return true;
+    };
+
+    (0..8)
+        .map(|bits| a * 8 + bits)
+        .any(|a| a != 0 && first_output(a, x, y) == last && outputs_itself(a, rest, x, y))
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // `a` has three bits for every number output
+    let bits = (size.clamp(1, 21) * 3) as u32;
+    let a = rng.random_range(1_u64 << (bits - 3)..1 << bits);
+
+    let program = loop {
+        let (x, y) = (rng.random_range(0..8), rng.random_range(0..8));
+        if outputs_itself(0, &program(x, y), x, y) {
+            break program(x, y);
+        }
+    };
+    let program = program.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+    format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, program.join(","))
+}
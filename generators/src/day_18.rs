@@ -0,0 +1,56 @@
+use std::collections::VecDeque;
+
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// The number of bytes that fall, at most 5039. The memory space is always the puzzle's 71 by 71.
+pub const SIZE: usize = 3450;
+
+const SIDE: usize = 71;
+
+// part 1 always lets this many bytes fall
+const FALLEN: usize = 1024;
+
+// whether there's still a way from the top left to the bottom right once `bytes` have fallen
+fn has_exit(bytes: &[(usize, usize)]) -> bool {
+    let mut blocked = Grid::new(SIDE, SIDE, false);
+    for &(x, y) in bytes {
+        blocked[Point::new(x as isize, y as isize)] = true;
+    }
+
+    let start = Point::new(0, 0);
+    blocked[start] = true;
+    let mut queue = VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        if p == Point::new(SIDE as isize - 1, SIDE as isize - 1) {
+            return true;
+        }
+        for n in blocked.neighbours(p).collect::<Vec<_>>() {
+            if !blocked[n] {
+                blocked[n] = true;
+                queue.push_back(n);
+            }
+        }
+    }
+    false
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // every cell but the start and the exit, in a random order
+    let mut cells = (0..SIDE * SIDE)
+        .map(|i| (i % SIDE, i / SIDE))
+        .filter(|&cell| cell != (0, 0) && cell != (SIDE - 1, SIDE - 1))
+        .collect::<Vec<_>>();
+    // like the puzzle, there's still a way out after the first kilobyte
+    cells.shuffle(rng);
+    while !has_exit(&cells[..FALLEN.min(size)]) {
+        cells.shuffle(rng);
+    }
+
+    let mut output = String::new();
+    for (x, y) in cells.into_iter().take(size) {
+        output += &format!("{},{}\n", x, y);
+    }
+    output
+}
@@ -0,0 +1,44 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// The number of designs.
+pub const SIZE: usize = 400;
+
+const TOWELS: usize = 450;
+
+fn stripes(rng: &mut StdRng, len: usize) -> String {
+    (0..len).map(|_| *['w', 'u', 'b', 'r', 'g'].choose(rng).unwrap()).collect()
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut towels = Vec::new();
+    while towels.len() < TOWELS {
+        let len = rng.random_range(1..=8);
+        let towel = stripes(rng, len);
+        // like the puzzle, there's no plain red towel, so not every design is possible
+        if towel != "r" && !towels.contains(&towel) {
+            towels.push(towel);
+        }
+    }
+
+    let mut output = towels.join(", ");
+    output += "\n\n";
+
+    for _ in 0..size {
+        let len = rng.random_range(20..=60);
+        // about half the designs are made from towels, and so definitely possible
+        let design = if rng.random_bool(0.5) {
+            let mut design = String::new();
+            while design.len() < len {
+                design += towels.choose(rng).unwrap();
+            }
+            design
+        } else {
+            stripes(rng, len)
+        };
+        output += &design;
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,30 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of reports.
+pub const SIZE: usize = 1000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(5..=8);
+        let direction = if rng.random_bool(0.5) { 1 } else { -1 };
+
+        let mut levels = vec![rng.random_range(10..90)];
+        for _ in 1..len {
+            // mostly safe steps, with the odd flat, too large or backwards one
+            let step = match rng.random_range(0..20) {
+                0 => 0,
+                1 => rng.random_range(4..8) * direction,
+                2 => -direction * rng.random_range(1..=3),
+                _ => rng.random_range(1..=3) * direction,
+            };
+            levels.push(levels[levels.len() - 1] + step);
+        }
+
+        let levels = levels.iter().map(|level| level.to_string()).collect::<Vec<_>>();
+        output += &levels.join(" ");
+        output.push('\n');
+    }
+    output
+}
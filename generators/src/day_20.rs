@@ -0,0 +1,52 @@
+use grid::{Dir, Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// The width and height of the racetrack, rounded up to an odd number.
+pub const SIZE: usize = 141;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    // the track only runs through odd cells and the walls between them, so two bits of track
+    // are never next to each other unless one follows the other
+    let rooms = size as isize / 2;
+    let contains = |p: Point| p.x >= 0 && p.y >= 0 && p.x < rooms && p.y < rooms;
+
+    // a random depth first search, where the longest path it finds becomes the track
+    let start = Point::new(0, rooms - 1);
+    let mut visited = Grid::new(rooms as usize, rooms as usize, false);
+    visited[start] = true;
+    let mut stack = vec![start];
+    let mut track = stack.clone();
+    while let Some(&room) = stack.last() {
+        let mut dirs = Dir::ALL;
+        dirs.shuffle(rng);
+        let next = dirs.into_iter().map(|dir| room.step(dir)).find(|&next| contains(next) && !visited[next]);
+
+        match next {
+            Some(next) => {
+                visited[next] = true;
+                stack.push(next);
+                if stack.len() > track.len() {
+                    track = stack.clone();
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    let widen = |p: Point| p * 2 + Point::new(1, 1);
+    let mut map = Grid::new(size, size, '#');
+    for pair in track.windows(2) {
+        let (a, b) = (widen(pair[0]), widen(pair[1]));
+        map[a] = '.';
+        map[Point::new((a.x + b.x) / 2, (a.y + b.y) / 2)] = '.';
+        map[b] = '.';
+    }
+    map[widen(track[0])] = 'S';
+    map[widen(track[track.len() - 1])] = 'E';
+
+    map.to_string()
+}
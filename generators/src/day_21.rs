@@ -0,0 +1,14 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of door codes.
+pub const SIZE: usize = 5;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut output = String::new();
+    for _ in 0..size {
+        output += &format!("{:03}A\n", rng.random_range(0..1000));
+    }
+    output
+}
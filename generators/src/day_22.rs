@@ -0,0 +1,14 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of buyers.
+pub const SIZE: usize = 1700;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size {
+        // secret numbers are pruned to 24 bits, so bigger ones never come up
+        output += &format!("{}\n", rng.random_range(1..1 << 24));
+    }
+    output
+}
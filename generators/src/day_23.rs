@@ -0,0 +1,50 @@
+use std::collections::BTreeSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The number of computers, at most 676.
+pub const SIZE: usize = 520;
+
+// the size of the LAN party hidden in the network
+const PARTY: usize = 13;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut names = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    names.truncate(size.clamp(2, names.len()));
+
+    // links are stored lowest name first, so each one is only made once
+    let mut links = BTreeSet::new();
+    let mut link = |a: usize, b: usize| {
+        if a != b {
+            links.insert((a.min(b), a.max(b)));
+        }
+    };
+
+    // everyone at the party is linked to everyone else at it...
+    let party = PARTY.min(names.len());
+    for a in 0..party {
+        for b in a + 1..party {
+            link(a, b);
+        }
+    }
+
+    // ...and otherwise computers are linked at random, about six links each
+    for a in 0..names.len() {
+        for _ in 0..6 {
+            link(a, rng.random_range(0..names.len()));
+        }
+    }
+
+    let mut lines = links
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", names[a], names[b]))
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+/// The number of bits in each of the two numbers being added, at most 63 so that their sum
+/// fits in a `u64`.
+pub const SIZE: usize = 45;
+
+// how many pairs of gates have their outputs swapped
+const SWAPS: usize = 4;
+
+struct Gate {
+    left: String,
+    op: &'static str,
+    right: String,
+    output: String,
+}
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let bits = size.clamp(SWAPS + 2, 63);
+
+    let mut used = HashSet::new();
+    let mut wire = |rng: &mut StdRng| loop {
+        let name = (0..3).map(|_| rng.random_range(b'a'..b'x') as char).collect::<String>();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let gate = |left: &str, op, right: &str, output: &str| Gate {
+        left: left.to_string(),
+        op,
+        right: right.to_string(),
+        output: output.to_string(),
+    };
+
+    // a ripple carry adder, one full adder per bit:
+    // sum = x ^ y, z = sum ^ carry_in, carry_out = (x & y) | (sum & carry_in)
+    let mut cells = Vec::new();
+    let mut gates = vec![gate("x00", "XOR", "y00", "z00")];
+    let mut carry = wire(rng);
+    gates.push(gate("x00", "AND", "y00", &carry));
+    for bit in 1..bits {
+        let (x, y, z) = (format!("x{:02}", bit), format!("y{:02}", bit), format!("z{:02}", bit));
+        let (sum, both, passed) = (wire(rng), wire(rng), wire(rng));
+        let carry_out = if bit == bits - 1 { format!("z{:02}", bits) } else { wire(rng) };
+
+        let first = gates.len();
+        gates.push(gate(&x, "XOR", &y, &sum));
+        gates.push(gate(&x, "AND", &y, &both));
+        gates.push(gate(&sum, "XOR", &carry, &z));
+        gates.push(gate(&sum, "AND", &carry, &passed));
+        gates.push(gate(&both, "OR", &passed, &carry_out));
+        cells.push(first);
+
+        carry = carry_out;
+    }
+
+    // swap outputs inside a few of the full adders, like the puzzle does. Only pairs where
+    // neither gate feeds the other, so the circuit never loops back on itself:
+    // (sum, both), (z, both), (z, passed) or (z, carry_out)
+    let mut cells = cells[..cells.len() - 1].to_vec();
+    cells.shuffle(rng);
+    for &first in cells.iter().take(SWAPS) {
+        let (a, b) = *[(0, 1), (2, 1), (2, 3), (2, 4)].choose(rng).unwrap();
+        let output = gates[first + a].output.clone();
+        gates[first + a].output = std::mem::replace(&mut gates[first + b].output, output);
+    }
+
+    let mut output = String::new();
+    for prefix in ['x', 'y'] {
+        for bit in 0..bits {
+            output += &format!("{}{:02}: {}\n", prefix, bit, rng.random_range(0..2));
+        }
+    }
+    output.push('\n');
+
+    gates.shuffle(rng);
+    for gate in gates {
+        let (left, right) = if rng.random_bool(0.5) { (gate.left, gate.right) } else { (gate.right, gate.left) };
+        output += &format!("{} {} {} -> {}\n", left, gate.op, right, gate.output);
+    }
+    output
+}
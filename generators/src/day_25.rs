@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of locks and keys together.
+pub const SIZE: usize = 500;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut schematics = Vec::new();
+    for _ in 0..size {
+        let heights = (0..5).map(|_| rng.random_range(0..=5)).collect::<Vec<_>>();
+        let lock = rng.random_bool(0.5);
+
+        // locks hang down from a full top row, keys stand up from a full bottom row
+        let rows = (0..7)
+            .map(|row| {
+                let height_from_edge = if lock { row } else { 6 - row };
+                heights
+                    .iter()
+                    .map(|&height| if height_from_edge <= height { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        schematics.push(rows.join("\n") + "\n");
+    }
+    schematics.join("\n")
+}
@@ -0,0 +1,37 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// The number of lines of memory, each a few thousand chars long.
+pub const SIZE: usize = 6;
+
+const LINE_LEN: usize = 3000;
+
+const JUNK: [&str; 16] = [
+    "where()", "from()", "select(", "what()", "who()", "how()", "why()", "when()",
+    "'", "[", "]", "<", ">", "{", "}", "@",
+];
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < LINE_LEN {
+            let (a, b) = (rng.random_range(1..1000), rng.random_range(1..1000));
+            match rng.random_range(0..20) {
+                0..=5 => line += &format!("mul({},{})", a, b),
+                // almost instructions, which have to be ignored
+                6 => line += &format!("mul({},{}", a, b),
+                7 => line += &format!("mul[{},{}]", a, b),
+                8 => line += &format!("mul ( {},{})", a, b),
+                9 => line += &format!("mul({},{})", a * 10 + 7, b),
+                10 => line += "do()",
+                11 => line += "don't()",
+                _ => line += JUNK.choose(rng).unwrap(),
+            }
+        }
+        output += &line;
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,16 @@
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+
+/// The width and height of the word search.
+pub const SIZE: usize = 140;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            output.push(*['X', 'M', 'A', 'S'].choose(rng).unwrap());
+        }
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,42 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The number of updates.
+pub const SIZE: usize = 200;
+
+const PAGES: usize = 49;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    // the pages in their one correct order, with a rule for every pair of them
+    let mut order = (10..100).collect::<Vec<u32>>();
+    order.shuffle(rng);
+    order.truncate(PAGES);
+
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut output = rules.join("\n");
+    output += "\n\n";
+
+    for _ in 0..size {
+        let len = rng.random_range(2..=11) * 2 + 1;
+        let mut update = order.clone();
+        update.shuffle(rng);
+        update.truncate(len);
+        // about half of the updates are already in the right order
+        if rng.random_bool(0.5) {
+            update.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+
+        let update = update.iter().map(|page| page.to_string()).collect::<Vec<_>>();
+        output += &update.join(",");
+        output.push('\n');
+    }
+    output
+}
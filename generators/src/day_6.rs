@@ -0,0 +1,19 @@
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// The width and height of the lab.
+pub const SIZE: usize = 130;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut lab = Grid::from_fn(size, size, |_| if rng.random_bool(0.05) { '#' } else { '.' });
+
+    // the guard needs somewhere to stand, even on a tiny lab that came out all obstacles
+    let open: Vec<Point> = lab.points().filter(|&p| lab[p] == '.').collect();
+    let guard = open.choose(rng).copied().unwrap_or(Point::new(0, 0));
+    lab[guard] = '^';
+
+    lab.to_string()
+}
@@ -0,0 +1,36 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of equations.
+pub const SIZE: usize = 850;
+
+// keeps the results well inside what the solver's f64s can hold exactly
+const MAX_RESULT: u64 = 100_000_000_000_000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut output = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(2..=12);
+        let numbers = (0..len).map(|_| rng.random_range(1..100)).collect::<Vec<u64>>();
+
+        // about half of them can be made true by some choice of operators
+        let result = if rng.random_bool(0.5) {
+            numbers[1..].iter().fold(numbers[0], |total, &n| {
+                let next = match rng.random_range(0..3) {
+                    0 => total.checked_mul(n),
+                    1 => format!("{}{}", total, n).parse().ok(),
+                    _ => None,
+                };
+                next.filter(|&next| next <= MAX_RESULT).unwrap_or(total + n)
+            })
+        } else {
+            let magnitude = rng.random_range(0..40);
+            rng.random_range(1..MAX_RESULT >> magnitude)
+        };
+
+        let numbers = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        output += &format!("{}: {}\n", result, numbers.join(" "));
+    }
+    output
+}
@@ -0,0 +1,24 @@
+use grid::{Grid, Point};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+/// The width and height of the map.
+pub const SIZE: usize = 50;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut map = Grid::new(size, size, '.');
+
+    // about one antenna for every twelve cells, four or so to a frequency
+    for _ in 0..size * size / 48 {
+        let frequency = *FREQUENCIES.choose(rng).unwrap() as char;
+        for _ in 0..rng.random_range(3..=4) {
+            let p = Point::new(rng.random_range(0..size) as isize, rng.random_range(0..size) as isize);
+            map[p] = frequency;
+        }
+    }
+
+    map.to_string()
+}
@@ -0,0 +1,18 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// The number of files on the disk.
+pub const SIZE: usize = 10000;
+
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut output = String::new();
+    for i in 0..size {
+        output += &rng.random_range(1..=9).to_string();
+        // free space goes between files, not after the last one
+        if i + 1 < size {
+            output += &rng.random_range(0..=9).to_string();
+        }
+    }
+    output.push('\n');
+    output
+}
@@ -0,0 +1,166 @@
+//! Random puzzle inputs for stress-testing the solvers. Each day has a generator that takes a
+//! `size` (what it measures depends on the day) and draws everything else from a seeded RNG,
+//! so the same day, size and seed always give the same input.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod day_1;
+mod day_2;
+mod day_3;
+mod day_4;
+mod day_5;
+mod day_6;
+mod day_7;
+mod day_8;
+mod day_9;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+pub type Generate = fn(&mut StdRng, usize) -> String;
+
+pub struct Generator {
+    /// A size close to the real puzzle input's.
+    pub default_size: usize,
+    pub generate: Generate,
+}
+
+pub fn get(day: u8) -> Option<Generator> {
+    let (default_size, generate): (usize, Generate) = match day {
+        1 => (day_1::SIZE, day_1::generate),
+        2 => (day_2::SIZE, day_2::generate),
+        3 => (day_3::SIZE, day_3::generate),
+        4 => (day_4::SIZE, day_4::generate),
+        5 => (day_5::SIZE, day_5::generate),
+        6 => (day_6::SIZE, day_6::generate),
+        7 => (day_7::SIZE, day_7::generate),
+        8 => (day_8::SIZE, day_8::generate),
+        9 => (day_9::SIZE, day_9::generate),
+        10 => (day_10::SIZE, day_10::generate),
+        11 => (day_11::SIZE, day_11::generate),
+        12 => (day_12::SIZE, day_12::generate),
+        13 => (day_13::SIZE, day_13::generate),
+        14 => (day_14::SIZE, day_14::generate),
+        15 => (day_15::SIZE, day_15::generate),
+        16 => (day_16::SIZE, day_16::generate),
+        17 => (day_17::SIZE, day_17::generate),
+        18 => (day_18::SIZE, day_18::generate),
+        19 => (day_19::SIZE, day_19::generate),
+        20 => (day_20::SIZE, day_20::generate),
+        21 => (day_21::SIZE, day_21::generate),
+        22 => (day_22::SIZE, day_22::generate),
+        23 => (day_23::SIZE, day_23::generate),
+        24 => (day_24::SIZE, day_24::generate),
+        25 => (day_25::SIZE, day_25::generate),
+        _ => return None,
+    };
+
+    Some(Generator { default_size, generate })
+}
+
+/// An input for `day`, or `None` if there's no such day. `size` defaults to the real input's.
+pub fn generate(day: u8, size: Option<usize>, seed: u64) -> Option<String> {
+    let generator = get(day)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    Some((generator.generate)(&mut rng, size.unwrap_or(generator.default_size)))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+
+    type Parser = fn(&str) -> Result<(), ParseError>;
+    type Solver = fn(&str) -> Result<String, ParseError>;
+
+    // both parts of each day, with the answers as strings
+    macro_rules! solvers {
+        ($($day:ident),*) => {
+            [$((
+                |input: &str| $day::part_1(input).map(|answer| answer.to_string()),
+                |input: &str| $day::part_2(input).map(|answer| answer.to_string()),
+            )),*]
+        };
+    }
+
+    const PARSERS: [Parser; 25] = [
+        day_1a::parse, day_2a::parse, day_3a::parse, day_4a::parse, day_5a::parse,
+        day_6a::parse, day_7a::parse, day_8a::parse, day_9a::parse, day_10a::parse,
+        day_11a::parse, day_12a::parse, day_13a::parse, day_14a::parse, day_15a::parse,
+        day_16a::parse, day_17a::parse, day_18a::parse, day_19a::parse, day_20a::parse,
+        day_21a::parse, day_22a::parse, day_23a::parse, day_24a::parse, day_25a::parse,
+    ];
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in 1..=25 {
+            assert_eq!(super::generate(day, None, 7), super::generate(day, None, 7), "day {}", day);
+        }
+        assert_ne!(super::generate(1, None, 7), super::generate(1, None, 8));
+    }
+
+    #[test]
+    fn every_input_parses() {
+        let solvers: [(Solver, Solver); 25] = solvers!(
+            day_1a, day_2a, day_3a, day_4a, day_5a, day_6a, day_7a, day_8a, day_9a, day_10a, day_11a, day_12a,
+            day_13a, day_14a, day_15a, day_16a, day_17a, day_18a, day_19a, day_20a, day_21a, day_22a, day_23a,
+            day_24a, day_25a
+        );
+
+        for ((day, parse), (part_1, part_2)) in (1..=25).zip(PARSERS).zip(solvers) {
+            for seed in 0..3 {
+                let input = super::generate(day, None, seed).unwrap();
+                if let Err(e) = parse(&input) {
+                    panic!("day {} seed {}: {}", day, seed, e.render(&input));
+                }
+
+                // and the first of them solves too
+                if seed > 0 {
+                    continue;
+                }
+                for (part, solve) in [(1, part_1), (2, part_2)] {
+                    if let Err(e) = solve(&input) {
+                        panic!("day {} seed {} part {}: {}", day, seed, part, e.render(&input));
+                    }
+                }
+            }
+        }
+
+        // day 24's solver used to only manage 45 bit adders, and not all of those
+        for size in [0, 8, 45, 63] {
+            for seed in 0..12 {
+                let input = super::generate(24, Some(size), seed).unwrap();
+                let swapped = day_24a::part_2(&input).unwrap_or_else(|e| panic!("size {} seed {}: {}", size, seed, e));
+                assert_eq!(swapped.split(',').count(), 8);
+            }
+        }
+    }
+
+    // every generator has to cope with sizes too small to make much of an input
+    #[test]
+    fn tiny_sizes() {
+        for (day, parse) in (1..=25).zip(PARSERS) {
+            for size in 0..=3 {
+                for seed in 0..8 {
+                    let input = super::generate(day, Some(size), seed).unwrap();
+                    if let Err(e) = parse(&input) {
+                        panic!("day {} size {} seed {}: {}", day, size, seed, e.render(&input));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
+
+use common::ParseError;
+
+/// A position on a grid. Signed so stepping off the top or left edge gives a point
+/// that simply isn't in the grid, instead of wrapping around.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Offsets to the four orthogonal neighbours, in the same order as [`Dir::ALL`].
+pub const NEIGHBOURS_4: [Point; 4] = [
+    Point::new(0, -1),
+    Point::new(1, 0),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+];
+
+/// Offsets to all eight neighbours, clockwise from straight up.
+pub const NEIGHBOURS_8: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// The point one cell over in `dir`.
+    pub fn step(self, dir: Dir) -> Self {
+        self + dir.delta()
+    }
+
+    /// The four orthogonal neighbours, whether or not they're on any grid.
+    pub fn neighbours(self) -> [Point; 4] {
+        NEIGHBOURS_4.map(|d| self + d)
+    }
+
+    /// All eight neighbours, whether or not they're on any grid.
+    pub fn neighbours_8(self) -> [Point; 8] {
+        NEIGHBOURS_8.map(|d| self + d)
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, n: isize) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+/// One of the four orthogonal directions, with y growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Clockwise, starting from up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn delta(self) -> Point {
+        NEIGHBOURS_4[self as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid where every cell is `f` of its position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+            .map(&mut f)
+            .collect();
+
+        Self { width, height, cells }
+    }
+
+    /// Parses a block of text with one cell per char. `tile` turns each char into a cell,
+    /// returning `None` for chars that aren't allowed; `expected` describes the allowed ones.
+    /// Every row has to be as wide as the first.
+    pub fn parse(
+        input: &str,
+        mut tile: impl FnMut(Point, char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let point = Point::new(row_width as isize, y as isize);
+                let cell = tile(point, c).ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(input, line, format!("a row {} cells wide", width)));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p).then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` so an empty grid doesn't ask for chunks of zero
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// All eight neighbours of `p` that are inside the grid.
+    pub fn neighbours_8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbours_8().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The position of the first cell, row by row, matching `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Draws the grid one char per cell, with a newline after every row.
+    pub fn render(&self, mut f: impl FnMut(Point, &T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for (p, cell) in self.iter() {
+            output.push(f(p, cell));
+            if p.x as usize == self.width - 1 {
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_8(Point::new(1, 1)).count(), 5);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let input = "#.\n.#";
+        let grid = Grid::parse(input, |_, c| matches!(c, '#' | '.').then_some(c == '#'), "`#` or `.`").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(grid.render(|_, &wall| if wall { '#' } else { '.' }), "#.\n.#\n");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let input = "..\n...";
+        let e = Grid::parse(input, |_, c| Some(c), "anything").unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 1, "a row 2 cells wide"));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Left.opposite(), Dir::Right);
+        assert_eq!(Point::new(2, 2).step(Dir::Up), Point::new(2, 1));
+    }
+}
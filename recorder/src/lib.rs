@@ -0,0 +1,195 @@
+//! Records the steps of a grid simulation as frames, to be saved as numbered PPM images or
+//! as one animated GIF.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use grid::{Grid, Point};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [110, 110, 110];
+pub const RED: Rgb = [220, 50, 40];
+pub const GREEN: Rgb = [40, 200, 70];
+pub const BLUE: Rgb = [50, 110, 230];
+pub const YELLOW: Rgb = [240, 210, 40];
+pub const BROWN: Rgb = [150, 95, 40];
+
+/// Colours that are easy to tell apart, for simulations with lots of things in them.
+pub const DISTINCT: [Rgb; 8] = [RED, GREEN, BLUE, YELLOW, BROWN, [200, 80, 200], [60, 200, 200], [240, 140, 30]];
+
+/// Every step of a simulation gets pushed to the recorder, which keeps the ones asked for:
+/// after skipping the first `skip` steps, one in every `every`, up to `limit` frames.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    skip: usize,
+    every: usize,
+    limit: usize,
+    steps: usize,
+    width: usize,
+    height: usize,
+    frames: Vec<Vec<Rgb>>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new(0, 1, usize::MAX)
+    }
+}
+
+impl Recorder {
+    pub fn new(skip: usize, every: usize, limit: usize) -> Self {
+        Self {
+            skip,
+            every: every.max(1),
+            limit,
+            steps: 0,
+            width: 0,
+            height: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Whether the next step pushed will be kept, so simulations can skip building a grid
+    /// just to have it thrown away.
+    pub fn wants(&self) -> bool {
+        self.steps >= self.skip && (self.steps - self.skip).is_multiple_of(self.every) && self.frames.len() < self.limit
+    }
+
+    /// Records one step, with each cell drawn as a single pixel of `colour`.
+    /// Every frame has to be the same size as the first.
+    pub fn push<T>(&mut self, grid: &Grid<T>, mut colour: impl FnMut(Point, &T) -> Rgb) {
+        if self.wants() {
+            if self.frames.is_empty() {
+                (self.width, self.height) = (grid.width(), grid.height());
+            }
+            assert_eq!(
+                (grid.width(), grid.height()),
+                (self.width, self.height),
+                "frames have to be the same size"
+            );
+
+            self.frames.push(grid.iter().map(|(p, cell)| colour(p, cell)).collect());
+        }
+        self.steps += 1;
+    }
+
+    /// Like `push`, but only builds the grid when the frame is going to be kept.
+    pub fn push_with<T>(&mut self, grid: impl FnOnce() -> Grid<T>, colour: impl FnMut(Point, &T) -> Rgb) {
+        if self.wants() {
+            self.push(&grid(), colour);
+        } else {
+            self.steps += 1;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    // a frame blown up so every cell is `scale` pixels across
+    fn scaled<'a>(&self, frame: &'a [Rgb], scale: usize) -> impl Iterator<Item = Rgb> + 'a {
+        let width = self.width;
+        (0..self.height * scale).flat_map(move |y| {
+            let row = &frame[y / scale * width..][..width];
+            (0..width * scale).map(move |x| row[x / scale])
+        })
+    }
+
+    /// Writes every frame to `dir` as `frame_00000.ppm`, `frame_00001.ppm` and so on.
+    pub fn write_ppm(&self, dir: &Path, scale: usize) -> io::Result<()> {
+        let scale = scale.max(1);
+        std::fs::create_dir_all(dir)?;
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            let mut file = BufWriter::new(File::create(dir.join(format!("frame_{:05}.ppm", i)))?);
+            write!(file, "P6\n{} {}\n255\n", self.width * scale, self.height * scale)?;
+            for pixel in self.scaled(frame, scale) {
+                file.write_all(&pixel)?;
+            }
+            file.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes every frame to one looping GIF, showing each for `delay` hundredths of a second.
+    /// GIFs can only hold 256 colours, so this fails if the frames use more.
+    pub fn write_gif(&self, path: &Path, scale: usize, delay: u16) -> io::Result<()> {
+        let scale = scale.max(1);
+        let (width, height) = (self.width * scale, self.height * scale);
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "frames are too big for a GIF");
+        let (gif_width, gif_height) = (u16::try_from(width).map_err(|_| too_big())?, u16::try_from(height).map_err(|_| too_big())?);
+
+        let mut palette = HashMap::new();
+        for pixel in self.frames.iter().flatten() {
+            let next = palette.len();
+            palette.entry(*pixel).or_insert(next);
+        }
+        if palette.len() > 256 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("a GIF can only have 256 colours, but the frames use {}", palette.len()),
+            ));
+        }
+
+        let mut colours = vec![BLACK; palette.len()];
+        for (&colour, &i) in &palette {
+            colours[i] = colour;
+        }
+
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = gif::Encoder::new(file, gif_width, gif_height, colours.as_flattened()).map_err(io::Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+        for frame in &self.frames {
+            let pixels = self.scaled(frame, scale).map(|pixel| palette[&pixel] as u8).collect::<Vec<_>>();
+            let mut frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_steps_asked_for() {
+        let grid = Grid::new(2, 1, 0);
+        let mut recorder = Recorder::new(1, 2, 2);
+        for _ in 0..10 {
+            recorder.push(&grid, |_, _| BLACK);
+        }
+        // steps 1 and 3, then it's full
+        assert_eq!(recorder.len(), 2);
+    }
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let grid = Grid::from_fn(2, 1, |p| p.x == 1);
+        let mut recorder = Recorder::default();
+        recorder.push(&grid, |_, &on| if on { WHITE } else { BLACK });
+
+        let dir = std::env::temp_dir().join(format!("recorder_test_{}", std::process::id()));
+        recorder.write_ppm(&dir, 2).unwrap();
+        let ppm = std::fs::read(dir.join("frame_00000.ppm")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let row = [BLACK, BLACK, WHITE, WHITE].concat();
+        assert_eq!(&ppm[header.len()..], [row.clone(), row].concat());
+    }
+}